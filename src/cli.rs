@@ -1,26 +1,245 @@
-use clap::Parser;
+use std::path::PathBuf;
+
+use clap::builder::BoolishValueParser;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser};
+
+use crate::config::{Config, ConfigError};
 
 #[derive(Parser, Debug)]
 #[command(name = "rust-fuzzy-clock")]
 #[command(about = "A fuzzy clock that translates time into natural language", long_about = None)]
 pub struct Args {
-    #[arg(short, long, default_value = "english")]
-    #[arg(help = "Language for time translation (english, spanish, portuguese)")]
+    #[arg(short, long, default_value = "auto", env = "FUZZY_CLOCK_LANGUAGE")]
+    #[arg(
+        help = "Language for time translation (english, spanish, portuguese, french, german, italian, japanese, russian, chinese), a BCP 47 tag such as pt-PT, or auto to follow the locale"
+    )]
     pub language: String,
 
-    #[arg(short, long, default_value = "exact")]
-    #[arg(help = "Level of fuzziness (exact, fuzzy, very-fuzzy, max-fuzzy)")]
+    #[arg(short, long, default_value = "exact", env = "FUZZY_CLOCK_FUZZINESS")]
+    #[arg(
+        help = "Level of fuzziness (exact, fuzzy, very-fuzzy, max-fuzzy, rounded, rounded-N, day, week, month, season)"
+    )]
     pub fuzziness: String,
 
-    #[arg(long = "24-hour", default_value_t = false)]
+    #[arg(long, default_value = "nearest", env = "FUZZY_CLOCK_ROUNDING")]
+    #[arg(help = "How fuzzy and rounded levels round the time (nearest, floor, ceiling)")]
+    pub rounding: String,
+
+    #[arg(
+        long = "24-hour",
+        default_value_t = false,
+        env = "FUZZY_CLOCK_24_HOUR",
+        value_parser = BoolishValueParser::new()
+    )]
     #[arg(help = "Use 24-hour format instead of 12-hour with AM/PM")]
     pub hour_24: bool,
 
-    #[arg(long = "include-units", default_value_t = false)]
+    #[arg(
+        long = "include-units",
+        default_value_t = false,
+        env = "FUZZY_CLOCK_INCLUDE_UNITS",
+        value_parser = BoolishValueParser::new()
+    )]
     #[arg(help = "Include 'hours' and 'minutes' labels in output")]
     pub include_units: bool,
+
+    #[arg(
+        long = "no-hour-names",
+        default_value_t = false,
+        env = "FUZZY_CLOCK_NO_HOUR_NAMES",
+        value_parser = BoolishValueParser::new()
+    )]
+    #[arg(help = "Say noon and midnight by their numbers (\"twelve o'clock\")")]
+    pub no_hour_names: bool,
+
+    #[arg(long = "day-periods", env = "FUZZY_CLOCK_DAY_PERIODS")]
+    #[arg(
+        help = "Where the max-fuzzy periods start, e.g. morning=5,afternoon=12,evening=17,night=22 (also early-morning, late-morning, noon)"
+    )]
+    pub day_periods: Option<String>,
+
+    #[arg(long, allow_negative_numbers = true, env = "FUZZY_CLOCK_LATITUDE")]
+    #[arg(
+        help = "Latitude in degrees (north positive); with --longitude, max-fuzzy periods follow the sun"
+    )]
+    pub latitude: Option<f64>,
+
+    #[arg(long, allow_negative_numbers = true, env = "FUZZY_CLOCK_LONGITUDE")]
+    #[arg(
+        help = "Longitude in degrees (east positive); with --latitude, max-fuzzy periods follow the sun"
+    )]
+    pub longitude: Option<f64>,
+
+    #[arg(long, env = "FUZZY_CLOCK_HEMISPHERE")]
+    #[arg(
+        help = "Hemisphere for the season level (north, south); defaults to the one --latitude is in, else north"
+    )]
+    pub hemisphere: Option<String>,
+
+    #[arg(long = "show-day", default_value = "off", env = "FUZZY_CLOCK_SHOW_DAY")]
+    #[arg(
+        help = "Follow the phrase with its day (off, weekday, relative for yesterday/today/tomorrow)"
+    )]
+    pub show_day: String,
+
+    #[arg(long, default_value = "present", env = "FUZZY_CLOCK_TENSE")]
+    #[arg(help = "Grammatical tense of the phrase (present, past, future)")]
+    pub tense: String,
+
+    #[arg(long, env = "FUZZY_CLOCK_STYLE")]
+    #[arg(
+        help = "Regional phrasing, for languages that have one (Spanish: latin-american, castilian; Portuguese: brazilian, european; German: northern, southern; Japanese: kanji, digits, kana; Chinese: simplified, traditional)"
+    )]
+    pub style: Option<String>,
+
+    #[arg(long, default_value = "plain", env = "FUZZY_CLOCK_FORMAT")]
+    #[arg(help = "Output format (plain, json, waybar, i3blocks, polybar)")]
+    pub format: String,
+
+    #[arg(long)]
+    #[arg(
+        help = "Translate this time instead of the current one (HH:MM, H:MMam, HH:MM:SS or RFC 3339)"
+    )]
+    pub at: Option<String>,
+
+    #[arg(short, long, env = "FUZZY_CLOCK_TIMEZONE")]
+    #[arg(help = "IANA time zone to use instead of the system one (e.g. America/Sao_Paulo)")]
+    pub timezone: Option<String>,
+
+    #[arg(
+        short,
+        long,
+        default_value_t = false,
+        env = "FUZZY_CLOCK_WATCH",
+        value_parser = BoolishValueParser::new()
+    )]
+    #[arg(help = "Keep running and print a new line whenever the output changes")]
+    pub watch: bool,
+
+    #[arg(long, env = "FUZZY_CLOCK_CONFIG")]
+    #[arg(help = "Read settings from this file instead of ~/.config/rust-fuzzy-clock/config.toml")]
+    pub config: Option<PathBuf>,
+
+    #[arg(long = "print-config", default_value_t = false)]
+    #[arg(help = "Print the effective settings as TOML and exit")]
+    pub print_config: bool,
+}
+
+impl Args {
+    /// Fills in every setting that was not given on the command line or
+    /// through a `FUZZY_CLOCK_*` environment variable from `config`.
+    pub fn merge_config(&mut self, config: &Config, matches: &ArgMatches) {
+        let from_config = |id: &str| {
+            !matches!(
+                matches.value_source(id),
+                Some(ValueSource::CommandLine | ValueSource::EnvVariable)
+            )
+        };
+
+        if from_config("language") {
+            merge(&mut self.language, &config.language);
+        }
+        if from_config("fuzziness") {
+            merge(&mut self.fuzziness, &config.fuzziness);
+        }
+        if from_config("rounding") {
+            merge(&mut self.rounding, &config.rounding);
+        }
+        if from_config("hour_24") {
+            merge(&mut self.hour_24, &config.hour_24);
+        }
+        if from_config("include_units") {
+            merge(&mut self.include_units, &config.include_units);
+        }
+        if from_config("no_hour_names") {
+            merge(&mut self.no_hour_names, &config.no_hour_names);
+        }
+        if from_config("day_periods") && config.day_periods.is_some() {
+            self.day_periods = config.day_periods.clone();
+        }
+        if from_config("latitude") && config.latitude.is_some() {
+            self.latitude = config.latitude;
+        }
+        if from_config("longitude") && config.longitude.is_some() {
+            self.longitude = config.longitude;
+        }
+        if from_config("hemisphere") && config.hemisphere.is_some() {
+            self.hemisphere = config.hemisphere.clone();
+        }
+        if from_config("show_day") {
+            merge(&mut self.show_day, &config.show_day);
+        }
+        if from_config("tense") {
+            merge(&mut self.tense, &config.tense);
+        }
+        if from_config("style") && config.style.is_some() {
+            self.style = config.style.clone();
+        }
+        if from_config("format") {
+            merge(&mut self.format, &config.format);
+        }
+        if from_config("timezone") && config.timezone.is_some() {
+            self.timezone = config.timezone.clone();
+        }
+        if from_config("watch") {
+            merge(&mut self.watch, &config.watch);
+        }
+        // `--at` pins the time, so it always wins over watch mode, however
+        // that was asked for
+        if self.at.is_some() {
+            self.watch = false;
+        }
+    }
+
+    /// The effective settings, in config file form.
+    pub fn to_config(&self) -> Config {
+        Config {
+            language: Some(self.language.clone()),
+            fuzziness: Some(self.fuzziness.clone()),
+            rounding: Some(self.rounding.clone()),
+            hour_24: Some(self.hour_24),
+            include_units: Some(self.include_units),
+            no_hour_names: Some(self.no_hour_names),
+            day_periods: self.day_periods.clone(),
+            latitude: self.latitude,
+            longitude: self.longitude,
+            hemisphere: self.hemisphere.clone(),
+            show_day: Some(self.show_day.clone()),
+            tense: Some(self.tense.clone()),
+            style: self.style.clone(),
+            format: Some(self.format.clone()),
+            timezone: self.timezone.clone(),
+            watch: Some(self.watch),
+        }
+    }
 }
 
-pub fn parse_args() -> Args {
-    Args::parse()
+fn merge<T: Clone>(field: &mut T, value: &Option<T>) {
+    if let Some(value) = value {
+        *field = value.clone();
+    }
+}
+
+/// Parses the command line and merges in the config file, so that explicit
+/// flags win over `FUZZY_CLOCK_*` variables, which win over the config file.
+pub fn parse_args() -> Result<Args, ConfigError> {
+    parse_args_from(std::env::args_os())
+}
+
+pub fn parse_args_from<I, T>(args: I) -> Result<Args, ConfigError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<std::ffi::OsString> + Clone,
+{
+    let matches = Args::command().get_matches_from(args);
+    let mut args = Args::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+
+    let config = match &args.config {
+        Some(path) => Config::load(path)?,
+        None => Config::load_default()?,
+    };
+    args.merge_config(&config, &matches);
+
+    Ok(args)
 }
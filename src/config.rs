@@ -0,0 +1,113 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Settings read from `config.toml`. Keys use the same names as the long CLI
+/// flags, and every key is optional.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuzziness: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rounding: Option<String>,
+    #[serde(rename = "24-hour", skip_serializing_if = "Option::is_none")]
+    pub hour_24: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_units: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_hour_names: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_periods: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hemisphere: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_day: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tense: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch: Option<bool>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        message: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io { path, source } => {
+                write!(f, "Cannot read config file {}: {}", path.display(), source)
+            }
+            ConfigError::Parse { path, message } => {
+                write!(f, "Invalid config file {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    /// Parses TOML config text. Unknown keys are rejected, and the error
+    /// message points at the offending line.
+    pub fn from_toml_str(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|err| err.to_string())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_toml_str(&text).map_err(|message| ConfigError::Parse {
+            path: path.to_path_buf(),
+            message,
+        })
+    }
+
+    /// Loads the config file at the default location, or an empty config if
+    /// there is none.
+    pub fn load_default() -> Result<Self, ConfigError> {
+        match default_path() {
+            Some(path) if path.is_file() => Self::load(&path),
+            _ => Ok(Config::default()),
+        }
+    }
+
+    pub fn to_toml_string(&self) -> String {
+        toml::to_string(self).unwrap_or_default()
+    }
+}
+
+/// `$XDG_CONFIG_HOME/rust-fuzzy-clock/config.toml`, falling back to
+/// `~/.config/rust-fuzzy-clock/config.toml`.
+pub fn default_path() -> Option<PathBuf> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_home.join("rust-fuzzy-clock").join("config.toml"))
+}
@@ -1,3 +1,7 @@
 pub mod cli;
+pub mod config;
+pub mod output;
+pub mod reading;
 pub mod time;
 pub mod translator;
+pub mod watch;
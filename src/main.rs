@@ -1,23 +1,163 @@
-use rust_fuzzy_clock::{cli, time, translator};
-use translator::{FuzzinessLevel, Language, get_translator};
+use rust_fuzzy_clock::output::OutputFormat;
+use rust_fuzzy_clock::reading::FuzzyReading;
+use rust_fuzzy_clock::{cli, time, translator, watch};
+use time::TimeInfo;
+use time::sun::Coordinates;
+use translator::calendar::Hemisphere;
+use translator::periods::DayPeriods;
+use translator::{FuzzinessLevel, Language, RoundingMode, ShowDay, Style, Tense, TranslateOptions};
 
 fn main() {
-    let args = cli::parse_args();
-
-    let language = Language::parse(&args.language).unwrap_or_else(|| {
-        eprintln!("Error: Unknown language '{}'", args.language);
+    let args = cli::parse_args().unwrap_or_else(|err| {
+        eprintln!("Error: {}", err);
         std::process::exit(1);
     });
 
+    if args.print_config {
+        print!("{}", args.to_config().to_toml_string());
+        return;
+    }
+
+    let (language, tag_style) = if args.language.eq_ignore_ascii_case("auto") {
+        Language::from_env().unwrap_or_else(|| {
+            eprintln!("Warning: No supported language in LC_ALL, LC_TIME or LANG; using English");
+            (Language::English, None)
+        })
+    } else {
+        Language::parse_tag(&args.language).unwrap_or_else(|| {
+            eprintln!("Error: Unknown language '{}'", args.language);
+            std::process::exit(1);
+        })
+    };
+
     let fuzziness = FuzzinessLevel::parse(&args.fuzziness).unwrap_or_else(|| {
         eprintln!("Error: Unknown fuzziness level '{}'", args.fuzziness);
         std::process::exit(1);
     });
 
-    let current_time = time::get_current_time();
-    let translator = get_translator(language);
-    let fuzzy_time =
-        translator.translate(&current_time, fuzziness, args.hour_24, args.include_units);
+    let rounding = RoundingMode::parse(&args.rounding).unwrap_or_else(|| {
+        eprintln!("Error: Unknown rounding mode '{}'", args.rounding);
+        std::process::exit(1);
+    });
+
+    let day_periods = args.day_periods.as_deref().map(|spec| {
+        DayPeriods::parse(spec).unwrap_or_else(|| {
+            eprintln!("Error: Invalid day periods '{}'", spec);
+            std::process::exit(1);
+        })
+    });
+
+    let location = match (args.latitude, args.longitude) {
+        (Some(latitude), Some(longitude)) => {
+            Some(Coordinates::new(latitude, longitude).unwrap_or_else(|| {
+                eprintln!(
+                    "Error: Invalid location {}, {} (expected a latitude between -90 and 90 and a longitude between -180 and 180)",
+                    latitude, longitude
+                );
+                std::process::exit(1);
+            }))
+        }
+        (None, None) => None,
+        _ => {
+            eprintln!("Error: --latitude and --longitude must be given together");
+            std::process::exit(1);
+        }
+    };
+
+    let hemisphere = args.hemisphere.as_deref().map(|name| {
+        Hemisphere::parse(name).unwrap_or_else(|| {
+            eprintln!("Error: Unknown hemisphere '{}'", name);
+            std::process::exit(1);
+        })
+    });
+
+    let show_day = ShowDay::parse(&args.show_day).unwrap_or_else(|| {
+        eprintln!("Error: Unknown show-day value '{}'", args.show_day);
+        std::process::exit(1);
+    });
+
+    let tense = Tense::parse(&args.tense).unwrap_or_else(|| {
+        eprintln!("Error: Unknown tense '{}'", args.tense);
+        std::process::exit(1);
+    });
+
+    let style = args.style.as_deref().map(|name| {
+        let style = Style::parse(name).unwrap_or_else(|| {
+            eprintln!("Error: Unknown style '{}'", name);
+            std::process::exit(1);
+        });
+        if !translator::get_translator(language).supports_style(style) {
+            eprintln!(
+                "Error: Style '{}' is not available for {}",
+                style.as_str(),
+                language.code()
+            );
+            std::process::exit(1);
+        }
+        style
+    });
+    // An explicit --style wins over the one implied by a tag like "pt-PT"
+    let style = style.or(tag_style);
+
+    let format = OutputFormat::parse(&args.format).unwrap_or_else(|| {
+        eprintln!("Error: Unknown output format '{}'", args.format);
+        std::process::exit(1);
+    });
+
+    let timezone = args.timezone.as_deref().map(|name| {
+        time::parse_timezone(name).unwrap_or_else(|err| {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        })
+    });
+
+    let options = TranslateOptions::new()
+        .level(fuzziness)
+        .rounding(rounding)
+        .use_24h(args.hour_24)
+        .include_units(args.include_units)
+        .hour_names(!args.no_hour_names)
+        .day_periods(day_periods)
+        .location(location)
+        .hemisphere(hemisphere)
+        .show_day(show_day)
+        .tense(tense)
+        .style(style);
+
+    let now = || match timezone {
+        Some(tz) => TimeInfo::now_in(tz),
+        None => time::get_current_time(),
+    };
+
+    // `--at` pins the time, so there is nothing to keep watching
+    if (args.watch || format.keeps_running()) && args.at.is_none() {
+        // Re-read the date too, so that "today" follows midnight
+        let read = || {
+            let now = now();
+            FuzzyReading::new(language, &now, &options.today(now.date()))
+        };
+        let render = |reading: &FuzzyReading| format.render(reading);
+        // A closed pipe (e.g. the status bar exited) just ends the loop
+        let _ = watch::run(read, render, &mut std::io::stdout());
+        return;
+    }
+
+    let current_time = match (&args.at, timezone) {
+        (Some(input), Some(tz)) => time::parse_time_in(input, tz),
+        (Some(input), None) => time::parse_time(input),
+        (None, _) => Ok(now()),
+    }
+    .unwrap_or_else(|err| {
+        eprintln!("Error: {}", err);
+        std::process::exit(1);
+    });
+    // A bare `--at 18:30` is today's, so that the sun can be placed
+    let today = now();
+    let current_time = match (current_time.date(), today.date(), today.utc_offset()) {
+        (None, Some(date), Some(offset)) => current_time.on_date(date, offset),
+        _ => current_time,
+    };
+    let reading = FuzzyReading::new(language, &current_time, &options.today(today.date()));
 
-    println!("{}", fuzzy_time);
+    println!("{}", format.render(&reading));
 }
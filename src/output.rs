@@ -0,0 +1,77 @@
+use serde::Serialize;
+
+use crate::reading::FuzzyReading;
+use crate::translator::{DayPeriod, FuzzinessLevel};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Plain,
+    Json,
+    /// Waybar custom module JSON (`text`, `tooltip`, `class`).
+    Waybar,
+    /// i3blocks/i3bar lines: full text, short text and color.
+    I3blocks,
+    /// Plain lines for polybar's `tail = true` scripts; keeps running.
+    Polybar,
+}
+
+#[derive(Serialize)]
+struct WaybarOutput<'a> {
+    text: &'a str,
+    tooltip: String,
+    class: DayPeriod,
+}
+
+impl OutputFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "plain" | "text" => Some(OutputFormat::Plain),
+            "json" => Some(OutputFormat::Json),
+            "waybar" => Some(OutputFormat::Waybar),
+            "i3blocks" | "i3bar" => Some(OutputFormat::I3blocks),
+            "polybar" => Some(OutputFormat::Polybar),
+            _ => None,
+        }
+    }
+
+    /// Whether the format is meant for a long-running process that prints a
+    /// line per change, as if `--watch` had been given.
+    pub fn keeps_running(&self) -> bool {
+        matches!(self, OutputFormat::Polybar)
+    }
+
+    /// Renders a reading without the trailing newline. Only `I3blocks`
+    /// produces more than one line.
+    pub fn render(&self, reading: &FuzzyReading) -> String {
+        match self {
+            OutputFormat::Plain | OutputFormat::Polybar => reading.phrase.clone(),
+            OutputFormat::Json => {
+                serde_json::to_string(reading).expect("FuzzyReading is always serializable")
+            }
+            OutputFormat::Waybar => {
+                let output = WaybarOutput {
+                    text: &reading.phrase,
+                    tooltip: reading.rephrase(FuzzinessLevel::Exact),
+                    class: reading.period,
+                };
+                serde_json::to_string(&output).expect("Waybar output is always serializable")
+            }
+            OutputFormat::I3blocks => format!(
+                "{}\n{}\n{}",
+                reading.phrase,
+                reading.rephrase(FuzzinessLevel::VeryFuzzy),
+                period_color(reading.period)
+            ),
+        }
+    }
+}
+
+fn period_color(period: DayPeriod) -> &'static str {
+    match period {
+        DayPeriod::Morning | DayPeriod::LateMorning => "#EBCB8B",
+        DayPeriod::Noon | DayPeriod::Afternoon => "#A3BE8C",
+        DayPeriod::Evening => "#D08770",
+        DayPeriod::Dawn | DayPeriod::Dusk | DayPeriod::Twilight => "#B48EAD",
+        DayPeriod::EarlyMorning | DayPeriod::Night => "#81A1C1",
+    }
+}
@@ -0,0 +1,125 @@
+use serde::Serialize;
+
+use crate::time::TimeInfo;
+use crate::translator::{
+    DayPeriod, FuzzinessLevel, Language, Tense, TimeTranslator, TranslateOptions, get_translator,
+};
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// A translated time together with the data it was derived from, for
+/// programs that consume the clock instead of reading the text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FuzzyReading {
+    pub phrase: String,
+    /// ISO 639-1 code of the phrase's language.
+    pub language: &'static str,
+    pub fuzziness: FuzzinessLevel,
+    pub tense: Tense,
+    pub hour24: u32,
+    pub minute: u32,
+    pub bucket: TimeBucket,
+    pub period: DayPeriod,
+    #[serde(skip)]
+    source: Source,
+}
+
+/// What a reading was translated from, kept so it can be rephrased.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Source {
+    language: Language,
+    time: TimeInfo,
+    options: TranslateOptions,
+}
+
+/// The range of times that produce the same phrase, in minutes since
+/// midnight (both ends inclusive). `start_minute` is greater than
+/// `end_minute` when the range wraps past midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct TimeBucket {
+    pub start_minute: u32,
+    pub end_minute: u32,
+}
+
+impl FuzzyReading {
+    pub fn new(language: Language, time: &TimeInfo, options: &TranslateOptions) -> Self {
+        let translator = get_translator(language);
+        let phrase = translator.translate_with(time, options);
+
+        FuzzyReading {
+            bucket: bucket(translator.as_ref(), time, options, &phrase),
+            period: translator.day_period(time, options),
+            language: language.code(),
+            fuzziness: options.level,
+            tense: options.tense,
+            hour24: time.hour24(),
+            minute: time.minute(),
+            phrase,
+            source: Source {
+                language,
+                time: *time,
+                options: *options,
+            },
+        }
+    }
+
+    /// The same time and options rendered at another fuzziness level.
+    pub fn rephrase(&self, level: FuzzinessLevel) -> String {
+        let Source {
+            language,
+            time,
+            options,
+        } = self.source;
+        get_translator(language).translate_with(&time, &options.level(level))
+    }
+}
+
+/// Walks outwards from `time` one minute at a time for as long as the
+/// translator keeps producing `phrase`.
+fn bucket(
+    translator: &dyn TimeTranslator,
+    time: &TimeInfo,
+    options: &TranslateOptions,
+    phrase: &str,
+) -> TimeBucket {
+    let current = time.hour24() * 60 + time.minute();
+    let same_phrase = |minute_of_day: u32| {
+        let time = time
+            .with_time(minute_of_day / 60, minute_of_day % 60)
+            .expect("minute of day is always a valid time");
+        translator.translate_with(&time, options) == phrase
+    };
+
+    let mut start = current;
+    let mut whole_day = true;
+    for _ in 1..MINUTES_PER_DAY {
+        let previous = (start + MINUTES_PER_DAY - 1) % MINUTES_PER_DAY;
+        if !same_phrase(previous) {
+            whole_day = false;
+            break;
+        }
+        start = previous;
+    }
+    // A phrase that never changes (the date levels) covers the whole day
+    // rather than wrapping around to end just before it started
+    if whole_day {
+        return TimeBucket {
+            start_minute: 0,
+            end_minute: MINUTES_PER_DAY - 1,
+        };
+    }
+
+    let mut end = current;
+    for _ in 1..MINUTES_PER_DAY {
+        let next = (end + 1) % MINUTES_PER_DAY;
+        if next == start || !same_phrase(next) {
+            break;
+        }
+        end = next;
+    }
+
+    TimeBucket {
+        start_minute: start,
+        end_minute: end,
+    }
+}
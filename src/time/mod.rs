@@ -1,28 +1,251 @@
-use chrono::{Local, Timelike};
+use std::fmt;
+use std::time::Duration;
 
+use chrono::{
+    DateTime, Days, FixedOffset, Local, NaiveDate, NaiveTime, Offset, TimeZone, Timelike, Utc,
+};
+use chrono_tz::Tz;
+
+pub mod sun;
+
+/// A validated wall-clock time. The 12-hour fields are derived from the
+/// stored 24-hour hour, so they can never contradict each other.
+///
+/// Times read from a clock or a timestamp also carry their date and UTC
+/// offset, which sun-aware day periods need; `new` leaves them out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TimeInfo {
-    pub hour: u32,
-    pub hour24: u32,
-    pub minute: u32,
-    pub is_pm: bool,
+    hour24: u32,
+    minute: u32,
+    date: Option<(NaiveDate, FixedOffset)>,
 }
 
+impl TimeInfo {
+    /// Builds a time from a 24-hour hour (0-23) and a minute (0-59).
+    pub fn new(hour24: u32, minute: u32) -> Result<Self, TimeError> {
+        if hour24 > 23 {
+            return Err(TimeError::InvalidHour(hour24));
+        }
+        if minute > 59 {
+            return Err(TimeError::InvalidMinute(minute));
+        }
+        Ok(TimeInfo {
+            hour24,
+            minute,
+            date: None,
+        })
+    }
+
+    /// The same wall-clock time on `date`, `offset` ahead of UTC.
+    pub fn on_date(self, date: NaiveDate, offset: FixedOffset) -> Self {
+        TimeInfo {
+            date: Some((date, offset)),
+            ..self
+        }
+    }
+
+    /// Another time on the same day (and at the same offset) as this one.
+    pub fn with_time(&self, hour24: u32, minute: u32) -> Result<Self, TimeError> {
+        let time = TimeInfo::new(hour24, minute)?;
+        Ok(TimeInfo {
+            date: self.date,
+            ..time
+        })
+    }
+
+    /// Builds a time from a 12-hour hour (1-12), a minute and an AM/PM flag.
+    pub fn from_12h(hour: u32, minute: u32, is_pm: bool) -> Result<Self, TimeError> {
+        let hour24 = match (hour, is_pm) {
+            (12, false) => 0,
+            (12, true) => 12,
+            (1..=11, false) => hour,
+            (1..=11, true) => hour + 12,
+            _ => return Err(TimeError::InvalidHour(hour)),
+        };
+        TimeInfo::new(hour24, minute)
+    }
+
+    /// Returns the current time in the given IANA time zone.
+    pub fn now_in(tz: Tz) -> Self {
+        TimeInfo::from(Utc::now().with_timezone(&tz))
+    }
+
+    /// Hour on a 12-hour clock (1-12).
+    pub fn hour(&self) -> u32 {
+        match self.hour24 {
+            0 => 12,
+            13..=23 => self.hour24 - 12,
+            _ => self.hour24,
+        }
+    }
+
+    /// Hour on a 24-hour clock (0-23).
+    pub fn hour24(&self) -> u32 {
+        self.hour24
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    pub fn is_pm(&self) -> bool {
+        self.hour24 >= 12
+    }
+
+    pub fn date(&self) -> Option<NaiveDate> {
+        self.date.map(|(date, _)| date)
+    }
+
+    pub fn utc_offset(&self) -> Option<FixedOffset> {
+        self.date.map(|(_, offset)| offset)
+    }
+
+    /// The instant this time stands for, if it carries its date.
+    pub fn to_utc(&self) -> Option<DateTime<Utc>> {
+        let (date, offset) = self.date?;
+        let time = NaiveTime::from_hms_opt(self.hour24, self.minute, 0)?;
+        let datetime = date.and_time(time).and_local_timezone(offset).single()?;
+        Some(datetime.with_timezone(&Utc))
+    }
+
+    /// The nearest time on a multiple of `step_minutes` past midnight,
+    /// rounding halfway cases up and wrapping past midnight.
+    pub fn round_to(&self, step_minutes: u32) -> TimeInfo {
+        self.snap_to(step_minutes, step_minutes.max(1) / 2)
+    }
+
+    /// The latest time on a multiple of `step_minutes` that is not after
+    /// this one.
+    pub fn floor_to(&self, step_minutes: u32) -> TimeInfo {
+        self.snap_to(step_minutes, 0)
+    }
+
+    /// The earliest time on a multiple of `step_minutes` that is not before
+    /// this one, wrapping past midnight.
+    pub fn ceil_to(&self, step_minutes: u32) -> TimeInfo {
+        self.snap_to(step_minutes, step_minutes.max(1) - 1)
+    }
+
+    fn snap_to(&self, step_minutes: u32, offset: u32) -> TimeInfo {
+        const MINUTES_PER_DAY: u32 = 24 * 60;
+        let step = step_minutes.max(1);
+        let minute_of_day = self.hour24 * 60 + self.minute;
+        let snapped = (minute_of_day + offset) / step * step % MINUTES_PER_DAY;
+        // Rounding up past midnight lands on the next day
+        let date = match self.date {
+            Some((date, offset)) if snapped < minute_of_day => date
+                .checked_add_days(Days::new(1))
+                .map(|date| (date, offset)),
+            date => date,
+        };
+        TimeInfo {
+            hour24: snapped / 60,
+            minute: snapped % 60,
+            date,
+        }
+    }
+}
+
+impl From<NaiveTime> for TimeInfo {
+    fn from(time: NaiveTime) -> Self {
+        TimeInfo {
+            hour24: time.hour(),
+            minute: time.minute(),
+            date: None,
+        }
+    }
+}
+
+impl<Z: TimeZone> From<DateTime<Z>> for TimeInfo {
+    fn from(datetime: DateTime<Z>) -> Self {
+        let offset = datetime.offset().fix();
+        TimeInfo::from(datetime.time()).on_date(datetime.date_naive(), offset)
+    }
+}
+
+/// Error returned when a time string cannot be turned into a `TimeInfo`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeError {
+    InvalidFormat(String),
+    InvalidHour(u32),
+    InvalidMinute(u32),
+    UnknownTimezone(String),
+}
+
+impl fmt::Display for TimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeError::InvalidFormat(input) => write!(
+                f,
+                "Invalid time '{}' (expected HH:MM, H:MMam, HH:MM:SS or an RFC 3339 timestamp)",
+                input
+            ),
+            TimeError::InvalidHour(hour) => write!(f, "Invalid hour {}", hour),
+            TimeError::InvalidMinute(minute) => {
+                write!(f, "Invalid minute {} (expected 0-59)", minute)
+            }
+            TimeError::UnknownTimezone(name) => write!(
+                f,
+                "Unknown timezone '{}' (expected an IANA name such as America/Sao_Paulo)",
+                name
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TimeError {}
+
 pub fn get_current_time() -> TimeInfo {
-    let now = Local::now();
-    let hour24 = now.hour();
-    let minute = now.minute();
-
-    let is_pm = hour24 >= 12;
-    let hour12 = match hour24 {
-        0 => 12,
-        13..=23 => hour24 - 12,
-        _ => hour24,
-    };
-
-    TimeInfo {
-        hour: hour12,
-        hour24,
-        minute,
-        is_pm,
+    TimeInfo::from(Local::now())
+}
+
+/// Time left until the next wall-clock minute starts.
+pub fn until_next_minute() -> Duration {
+    let now = Utc::now();
+    let elapsed = Duration::new(u64::from(now.second()), now.nanosecond() % 1_000_000_000);
+    Duration::from_secs(60).saturating_sub(elapsed)
+}
+
+/// Looks up an IANA time zone name (e.g. `Europe/Madrid`) in the bundled
+/// tz database.
+pub fn parse_timezone(name: &str) -> Result<Tz, TimeError> {
+    name.trim()
+        .parse::<Tz>()
+        .map_err(|_| TimeError::UnknownTimezone(name.to_string()))
+}
+
+/// Parses a user-supplied time such as `23:58`, `7:05pm`, `23:58:30` or
+/// `2024-05-01T15:47:00-03:00`. Seconds are accepted but ignored, and RFC 3339
+/// timestamps keep the wall-clock time of their own offset, along with their
+/// date.
+pub fn parse_time(input: &str) -> Result<TimeInfo, TimeError> {
+    parse_with(input, |datetime| datetime)
+}
+
+/// Like `parse_time`, but RFC 3339 timestamps are converted to `tz` first.
+/// Plain wall-clock inputs such as `23:58` are taken as already being in `tz`.
+pub fn parse_time_in(input: &str, tz: Tz) -> Result<TimeInfo, TimeError> {
+    parse_with(input, |datetime| datetime.with_timezone(&tz).fixed_offset())
+}
+
+fn parse_with<F>(input: &str, rfc3339_time: F) -> Result<TimeInfo, TimeError>
+where
+    F: Fn(DateTime<FixedOffset>) -> DateTime<FixedOffset>,
+{
+    let trimmed = input.trim();
+
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(trimmed) {
+        return Ok(TimeInfo::from(rfc3339_time(datetime)));
+    }
+
+    // Allow "7:05 pm" as well as "7:05pm"
+    let compact: String = trimmed.split_whitespace().collect();
+    let parsed = ["%H:%M", "%H:%M:%S", "%I:%M%p", "%I:%M:%S%p"]
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(&compact, format).ok());
+
+    match parsed {
+        Some(time) => Ok(TimeInfo::from(time)),
+        None => Err(TimeError::InvalidFormat(input.to_string())),
     }
 }
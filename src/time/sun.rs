@@ -0,0 +1,112 @@
+//! Where the sun is in the sky, for sun-aware day periods.
+//!
+//! Uses NOAA's general solar position approximation: the sun's declination
+//! and the equation of time as Fourier series of the day of the year. It is
+//! good to a minute or two of the published sunrise and sunset times and
+//! needs no network or ephemeris data.
+
+use std::f64::consts::PI;
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+
+/// A point on the Earth in degrees, north and east positive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coordinates {
+    latitude: f64,
+    longitude: f64,
+}
+
+// `new` only accepts finite numbers, so equality is total
+impl Eq for Coordinates {}
+
+impl Coordinates {
+    /// `None` unless the latitude is within -90..=90 and the longitude
+    /// within -180..=180.
+    pub fn new(latitude: f64, longitude: f64) -> Option<Self> {
+        ((-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude)).then_some(
+            Coordinates {
+                latitude,
+                longitude,
+            },
+        )
+    }
+
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+}
+
+/// The sun as seen from some place at some instant.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SunPosition {
+    /// Degrees above the horizon (negative below it), without refraction.
+    pub elevation: f64,
+    /// Degrees from the local meridian, negative before solar noon and
+    /// positive after it, in -180..180.
+    pub hour_angle: f64,
+    /// The sun's lowest elevation that day, at solar midnight.
+    pub lowest: f64,
+    /// The sun's highest elevation that day, at solar noon.
+    pub highest: f64,
+}
+
+impl SunPosition {
+    /// Elevation of the sun's centre at sunrise and sunset, allowing for
+    /// refraction and the size of its disc.
+    pub const HORIZON: f64 = -0.833;
+    /// Elevation at which civil twilight begins and ends.
+    pub const CIVIL_TWILIGHT: f64 = -6.0;
+
+    pub fn at(coordinates: Coordinates, instant: DateTime<Utc>) -> Self {
+        let minutes =
+            f64::from(instant.hour() * 60 + instant.minute()) + f64::from(instant.second()) / 60.0;
+        // Fractional year, in radians
+        let gamma =
+            2.0 * PI / 365.0 * (f64::from(instant.ordinal0()) + (minutes / 60.0 - 12.0) / 24.0);
+
+        let equation_of_time = 229.18
+            * (0.000075 + 0.001868 * gamma.cos()
+                - 0.032077 * gamma.sin()
+                - 0.014615 * (2.0 * gamma).cos()
+                - 0.040849 * (2.0 * gamma).sin());
+        let declination = 0.006918 - 0.399912 * gamma.cos() + 0.070257 * gamma.sin()
+            - 0.006758 * (2.0 * gamma).cos()
+            + 0.000907 * (2.0 * gamma).sin()
+            - 0.002697 * (3.0 * gamma).cos()
+            + 0.00148 * (3.0 * gamma).sin();
+
+        let solar_minutes = minutes + equation_of_time + 4.0 * coordinates.longitude;
+        let hour_angle = (solar_minutes / 4.0).rem_euclid(360.0) - 180.0;
+
+        let latitude = coordinates.latitude.to_radians();
+        let elevation = (latitude.sin() * declination.sin()
+            + latitude.cos() * declination.cos() * hour_angle.to_radians().cos())
+        .clamp(-1.0, 1.0)
+        .asin()
+        .to_degrees();
+
+        let declination = declination.to_degrees();
+        SunPosition {
+            elevation,
+            hour_angle,
+            lowest: (coordinates.latitude + declination).abs() - 90.0,
+            highest: 90.0 - (coordinates.latitude - declination).abs(),
+        }
+    }
+
+    /// Whether the sun is between the horizon and 6° below it.
+    pub fn in_twilight(&self) -> bool {
+        (Self::CIVIL_TWILIGHT..Self::HORIZON).contains(&self.elevation)
+    }
+
+    /// Whether the sun rises and later gets more than 6° below the horizon
+    /// that day, so that it has a dawn and a dusk rather than a long
+    /// twilight (white nights, polar twilight).
+    pub fn has_dawn_and_dusk(&self) -> bool {
+        self.highest >= Self::HORIZON && self.lowest < Self::CIVIL_TWILIGHT
+    }
+}
@@ -0,0 +1,100 @@
+//! Where a date falls in its month and season, for the month and season
+//! levels.
+//!
+//! Seasons follow the solstices and equinoxes, taken to be on the 21st of
+//! March, June, September and December: northern summer runs from 21 June
+//! to 20 September, when it is winter in the south.
+
+use chrono::{Datelike, Days, NaiveDate};
+
+use super::TranslateOptions;
+
+/// Which half of the Earth the seasons are for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Hemisphere {
+    #[default]
+    Northern,
+    Southern,
+}
+
+impl Hemisphere {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "north" | "northern" => Some(Hemisphere::Northern),
+            "south" | "southern" => Some(Hemisphere::Southern),
+            _ => None,
+        }
+    }
+
+    /// The options' hemisphere, else the one their location is in, else
+    /// northern.
+    pub fn of(options: &TranslateOptions) -> Self {
+        options
+            .hemisphere
+            .or_else(|| {
+                options.location.map(|location| {
+                    if location.latitude() < 0.0 {
+                        Hemisphere::Southern
+                    } else {
+                        Hemisphere::Northern
+                    }
+                })
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Season, in the order packs list their names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    pub const ALL: [Season; 4] = [
+        Season::Spring,
+        Season::Summer,
+        Season::Autumn,
+        Season::Winter,
+    ];
+
+    /// The season `date` is in, and which of its three months (from the
+    /// 21st to the 20th) it is.
+    pub fn of(date: NaiveDate, hemisphere: Hemisphere) -> (Season, Third) {
+        // Counting from the 21st makes each season start on the 1st
+        let shifted = date.checked_sub_days(Days::new(20)).unwrap_or(date);
+        // Months since the start of northern spring
+        let mut months = (shifted.month0() + 10) % 12;
+        if hemisphere == Hemisphere::Southern {
+            months = (months + 6) % 12;
+        }
+        let third = [Third::Early, Third::Middle, Third::Late][months as usize % 3];
+        (Season::ALL[months as usize / 3], third)
+    }
+
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// The beginning, middle or end of a month or season.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Third {
+    Early,
+    Middle,
+    Late,
+}
+
+impl Third {
+    /// Days 1-10 are early, 11-20 the middle and the rest late.
+    pub fn of_month(date: NaiveDate) -> Self {
+        match date.day() {
+            1..=10 => Third::Early,
+            11..=20 => Third::Middle,
+            _ => Third::Late,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use super::pack::bundled_pack;
+
+bundled_pack!(
+    /// Mandarin Chinese, driven by `packs/chinese.toml`.
+    ChineseTranslator,
+    "packs/chinese.toml"
+);
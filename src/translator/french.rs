@@ -0,0 +1,7 @@
+use super::pack::bundled_pack;
+
+bundled_pack!(
+    /// French, driven by `packs/french.toml`.
+    FrenchTranslator,
+    "packs/french.toml"
+);
@@ -0,0 +1,7 @@
+use super::pack::bundled_pack;
+
+bundled_pack!(
+    /// German, driven by `packs/german.toml`.
+    GermanTranslator,
+    "packs/german.toml"
+);
@@ -0,0 +1,7 @@
+use super::pack::bundled_pack;
+
+bundled_pack!(
+    /// Italian, driven by `packs/italian.toml`.
+    ItalianTranslator,
+    "packs/italian.toml"
+);
@@ -0,0 +1,7 @@
+use super::pack::bundled_pack;
+
+bundled_pack!(
+    /// Japanese, driven by `packs/japanese.toml`.
+    JapaneseTranslator,
+    "packs/japanese.toml"
+);
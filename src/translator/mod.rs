@@ -1,52 +1,577 @@
+pub mod calendar;
+pub mod chinese;
 pub mod english;
+pub mod french;
+pub mod german;
+pub mod italian;
+pub mod japanese;
+pub mod pack;
+pub mod periods;
+pub mod plural;
 pub mod portuguese;
+pub mod russian;
 pub mod spanish;
 
+use std::fmt;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize, Serializer};
+
 use crate::time::TimeInfo;
+use crate::time::sun::Coordinates;
+use calendar::Hemisphere;
+use periods::DayPeriods;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FuzzinessLevel {
     Exact,
     Fuzzy,
     VeryFuzzy,
     MaxFuzzy,
+    /// The time rounded to a multiple of `step_minutes` (a divisor of 60)
+    /// and said the way a clock face reads: "ten past three", "twenty-five to
+    /// four".
+    Rounded {
+        step_minutes: u32,
+    },
+    /// The weekday with its day period: "Tuesday afternoon", "late Sunday
+    /// night".
+    Day,
+    /// The part of the week: "midweek", "the weekend".
+    Week,
+    /// The part of the month: "early March", "mid-June", "late June".
+    Month,
+    /// The part of the season, by hemisphere: "early summer",
+    /// "mid-autumn".
+    Season,
 }
 
 impl FuzzinessLevel {
+    /// Step used by a plain `rounded`.
+    pub const DEFAULT_ROUNDING_STEP: u32 = 5;
+
+    /// Parses a level name; `rounded-N` rounds to N minutes and `rounded`
+    /// to `DEFAULT_ROUNDING_STEP`.
     pub fn parse(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "exact" => Some(FuzzinessLevel::Exact),
             "fuzzy" => Some(FuzzinessLevel::Fuzzy),
             "very-fuzzy" => Some(FuzzinessLevel::VeryFuzzy),
             "max-fuzzy" => Some(FuzzinessLevel::MaxFuzzy),
+            "day" => Some(FuzzinessLevel::Day),
+            "week" => Some(FuzzinessLevel::Week),
+            "month" => Some(FuzzinessLevel::Month),
+            "season" => Some(FuzzinessLevel::Season),
+            "rounded" => Some(FuzzinessLevel::Rounded {
+                step_minutes: Self::DEFAULT_ROUNDING_STEP,
+            }),
+            other => {
+                let step_minutes: u32 = other.strip_prefix("rounded-")?.parse().ok()?;
+                (step_minutes > 0 && 60 % step_minutes == 0)
+                    .then_some(FuzzinessLevel::Rounded { step_minutes })
+            }
+        }
+    }
+
+    /// Minutes between the times a `RoundingMode` rounds to at this level,
+    /// or `None` for exact times and the date levels. Max-fuzzy only rounds
+    /// to tell whether the time is around a named hour such as noon.
+    pub fn step_minutes(&self) -> Option<u32> {
+        match self {
+            FuzzinessLevel::Fuzzy => Some(15),
+            FuzzinessLevel::VeryFuzzy | FuzzinessLevel::MaxFuzzy => Some(30),
+            FuzzinessLevel::Rounded { step_minutes } => Some(*step_minutes),
+            FuzzinessLevel::Exact
+            | FuzzinessLevel::Day
+            | FuzzinessLevel::Week
+            | FuzzinessLevel::Month
+            | FuzzinessLevel::Season => None,
+        }
+    }
+
+    /// Whether this level describes the date (day, week, month, season)
+    /// rather than the time.
+    pub fn is_date_level(&self) -> bool {
+        matches!(
+            self,
+            FuzzinessLevel::Day
+                | FuzzinessLevel::Week
+                | FuzzinessLevel::Month
+                | FuzzinessLevel::Season
+        )
+    }
+}
+
+impl fmt::Display for FuzzinessLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FuzzinessLevel::Exact => write!(f, "exact"),
+            FuzzinessLevel::Fuzzy => write!(f, "fuzzy"),
+            FuzzinessLevel::VeryFuzzy => write!(f, "very-fuzzy"),
+            FuzzinessLevel::MaxFuzzy => write!(f, "max-fuzzy"),
+            FuzzinessLevel::Rounded { step_minutes } => write!(f, "rounded-{}", step_minutes),
+            FuzzinessLevel::Day => write!(f, "day"),
+            FuzzinessLevel::Week => write!(f, "week"),
+            FuzzinessLevel::Month => write!(f, "month"),
+            FuzzinessLevel::Season => write!(f, "season"),
+        }
+    }
+}
+
+/// Serialized as its `parse` name, e.g. `"very-fuzzy"` or `"rounded-10"`.
+impl Serialize for FuzzinessLevel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Coarse part of the day used by the max-fuzzy level.
+///
+/// The default `DayPeriods` only use `Morning`, `Afternoon`, `Evening` and
+/// `Night`; the others are for splitting the day more finely (Chinese 凌晨,
+/// 上午, 中午) or by the sun (`Dawn`, `Dusk` and `Twilight`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DayPeriod {
+    /// The small hours after midnight.
+    EarlyMorning,
+    /// Civil twilight before sunrise.
+    Dawn,
+    Morning,
+    /// The later part of the morning, before noon.
+    LateMorning,
+    /// Around midday.
+    Noon,
+    Afternoon,
+    /// Civil twilight after sunset.
+    Dusk,
+    Evening,
+    Night,
+    /// The sun just below the horizon on a day without both a dawn and a
+    /// dusk (white nights, polar twilight).
+    Twilight,
+}
+
+impl DayPeriod {
+    pub const ALL: [DayPeriod; 10] = [
+        DayPeriod::EarlyMorning,
+        DayPeriod::Dawn,
+        DayPeriod::Morning,
+        DayPeriod::LateMorning,
+        DayPeriod::Noon,
+        DayPeriod::Afternoon,
+        DayPeriod::Dusk,
+        DayPeriod::Evening,
+        DayPeriod::Night,
+        DayPeriod::Twilight,
+    ];
+
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.to_lowercase();
+        Self::ALL.into_iter().find(|period| period.as_str() == s)
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DayPeriod::EarlyMorning => "early-morning",
+            DayPeriod::Dawn => "dawn",
+            DayPeriod::Morning => "morning",
+            DayPeriod::LateMorning => "late-morning",
+            DayPeriod::Noon => "noon",
+            DayPeriod::Afternoon => "afternoon",
+            DayPeriod::Dusk => "dusk",
+            DayPeriod::Evening => "evening",
+            DayPeriod::Night => "night",
+            DayPeriod::Twilight => "twilight",
+        }
+    }
+}
+
+/// Whether the phrase is followed by the day it is on: "quarter past three,
+/// Monday" or "quarter past three, today". The day and week levels already
+/// say it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShowDay {
+    #[default]
+    Off,
+    Weekday,
+    /// "yesterday", "today" or "tomorrow" relative to the options' `today`,
+    /// and the weekday for any other day.
+    Relative,
+}
+
+impl ShowDay {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "off" | "none" => Some(ShowDay::Off),
+            "weekday" => Some(ShowDay::Weekday),
+            "relative" => Some(ShowDay::Relative),
+            _ => None,
+        }
+    }
+}
+
+/// Grammatical tense of the phrase: "quarter past three", "it was quarter
+/// past three" or "it will be quarter past three".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Tense {
+    #[default]
+    Present,
+    Past,
+    Future,
+}
+
+impl Tense {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "present" => Some(Tense::Present),
+            "past" => Some(Tense::Past),
+            "future" => Some(Tense::Future),
+            _ => None,
+        }
+    }
+}
+
+/// Which way the fuzzy, very fuzzy and rounded levels round the time.
+///
+/// `Nearest` keeps each language's hand-tuned fuzzy phrases. `Floor` never
+/// names a time that has not happened yet ("about quarter past" until half
+/// past) and `Ceiling` always names the next one ("almost half past" from
+/// quarter past on).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RoundingMode {
+    #[default]
+    Nearest,
+    Floor,
+    Ceiling,
+}
+
+impl RoundingMode {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "nearest" => Some(RoundingMode::Nearest),
+            "floor" => Some(RoundingMode::Floor),
+            "ceiling" => Some(RoundingMode::Ceiling),
+            _ => None,
+        }
+    }
+
+    /// Rounds `time` to a multiple of `step_minutes` past midnight.
+    pub fn round(&self, time: &TimeInfo, step_minutes: u32) -> TimeInfo {
+        match self {
+            RoundingMode::Nearest => time.round_to(step_minutes),
+            RoundingMode::Floor => time.floor_to(step_minutes),
+            RoundingMode::Ceiling => time.ceil_to(step_minutes),
+        }
+    }
+}
+
+/// A regional or stylistic variant of a language's phrasing. Each language
+/// supports its own subset, see `TimeTranslator::supports_style`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum Style {
+    /// German "Viertel nach drei", "Viertel vor vier".
+    Northern,
+    /// German "viertel vier", "dreiviertel vier".
+    Southern,
+    /// Japanese kanji numerals, "三時四十七分".
+    Kanji,
+    /// Japanese Arabic numerals, "3時47分".
+    Digits,
+    /// Japanese hiragana reading, "さんじ よんじゅうななふん".
+    Kana,
+    /// Brazilian Portuguese, "quinze para as quatro", "dezesseis".
+    Brazilian,
+    /// European Portuguese, "um quarto para as quatro", "dezasseis".
+    European,
+    /// Latin American Spanish, "cuarto para las cuatro".
+    LatinAmerican,
+    /// Castilian Spanish, "las cuatro menos cuarto".
+    Castilian,
+    /// Chinese simplified characters, "三点半".
+    Simplified,
+    /// Chinese traditional characters, "三點半".
+    Traditional,
+}
+
+impl Style {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "northern" => Some(Style::Northern),
+            "southern" => Some(Style::Southern),
+            "kanji" => Some(Style::Kanji),
+            "digits" => Some(Style::Digits),
+            "kana" => Some(Style::Kana),
+            "brazilian" => Some(Style::Brazilian),
+            "european" => Some(Style::European),
+            "latin-american" => Some(Style::LatinAmerican),
+            "castilian" => Some(Style::Castilian),
+            "simplified" => Some(Style::Simplified),
+            "traditional" => Some(Style::Traditional),
             _ => None,
         }
     }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Style::Northern => "northern",
+            Style::Southern => "southern",
+            Style::Kanji => "kanji",
+            Style::Digits => "digits",
+            Style::Kana => "kana",
+            Style::Brazilian => "brazilian",
+            Style::European => "european",
+            Style::LatinAmerican => "latin-american",
+            Style::Castilian => "castilian",
+            Style::Simplified => "simplified",
+            Style::Traditional => "traditional",
+        }
+    }
+}
+
+/// Output settings passed to `TimeTranslator::translate_with`.
+///
+/// Build it with `TranslateOptions::new()` and the chained setters; the
+/// defaults match the CLI defaults (exact, 12-hour, no unit labels, present
+/// tense, the language's default style, rounding to the nearest time, noon
+/// and midnight by name, the language's day periods, no location, no day,
+/// the location's hemisphere).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TranslateOptions {
+    pub level: FuzzinessLevel,
+    pub use_24h: bool,
+    pub include_units: bool,
+    pub tense: Tense,
+    pub style: Option<Style>,
+    pub rounding: RoundingMode,
+    pub hour_names: bool,
+    pub day_periods: Option<DayPeriods>,
+    pub location: Option<Coordinates>,
+    pub show_day: ShowDay,
+    pub today: Option<NaiveDate>,
+    pub hemisphere: Option<Hemisphere>,
+}
+
+impl Default for TranslateOptions {
+    fn default() -> Self {
+        TranslateOptions {
+            level: FuzzinessLevel::Exact,
+            use_24h: false,
+            include_units: false,
+            tense: Tense::Present,
+            style: None,
+            rounding: RoundingMode::Nearest,
+            hour_names: true,
+            day_periods: None,
+            location: None,
+            show_day: ShowDay::Off,
+            today: None,
+            hemisphere: None,
+        }
+    }
+}
+
+impl TranslateOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn level(mut self, level: FuzzinessLevel) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn use_24h(mut self, use_24h: bool) -> Self {
+        self.use_24h = use_24h;
+        self
+    }
+
+    pub fn include_units(mut self, include_units: bool) -> Self {
+        self.include_units = include_units;
+        self
+    }
+
+    pub fn tense(mut self, tense: Tense) -> Self {
+        self.tense = tense;
+        self
+    }
+
+    /// Selects a style; `None` (or a style the language lacks) uses the
+    /// language's default phrasing.
+    pub fn style(mut self, style: Option<Style>) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Whether hours with a name ("noon", "midnight") are called by it
+    /// rather than by their number.
+    pub fn hour_names(mut self, hour_names: bool) -> Self {
+        self.hour_names = hour_names;
+        self
+    }
+
+    /// Sets where the max-fuzzy periods start; `None` uses the language's
+    /// own periods.
+    pub fn day_periods(mut self, day_periods: Option<DayPeriods>) -> Self {
+        self.day_periods = day_periods;
+        self
+    }
+
+    /// Makes the max-fuzzy periods follow the sun at `location` (dawn,
+    /// morning, afternoon, dusk, evening, night) for times that carry their
+    /// date.
+    pub fn location(mut self, location: Option<Coordinates>) -> Self {
+        self.location = location;
+        self
+    }
+
+    /// Follows each phrase with the day it is on, for times that carry
+    /// their date.
+    pub fn show_day(mut self, show_day: ShowDay) -> Self {
+        self.show_day = show_day;
+        self
+    }
+
+    /// The date `ShowDay::Relative` counts from.
+    pub fn today(mut self, today: Option<NaiveDate>) -> Self {
+        self.today = today;
+        self
+    }
+
+    /// Sets the hemisphere for the season level; `None` uses the
+    /// location's, or else the northern one.
+    pub fn hemisphere(mut self, hemisphere: Option<Hemisphere>) -> Self {
+        self.hemisphere = hemisphere;
+        self
+    }
 }
 
 pub trait TimeTranslator {
+    fn translate_with(&self, time: &TimeInfo, options: &TranslateOptions) -> String;
+
+    /// The part of the day `time` falls in, as named by the max-fuzzy level.
+    fn day_period(&self, time: &TimeInfo, options: &TranslateOptions) -> DayPeriod {
+        periods::day_period(time, options, None)
+    }
+
+    /// Whether `style` is one of this language's phrasings.
+    fn supports_style(&self, _style: Style) -> bool {
+        false
+    }
+
+    #[deprecated(note = "use `translate_with` with `TranslateOptions` instead")]
     fn translate(
         &self,
         time: &TimeInfo,
         level: FuzzinessLevel,
         use_24h: bool,
         include_units: bool,
-    ) -> String;
+    ) -> String {
+        let options = TranslateOptions::new()
+            .level(level)
+            .use_24h(use_24h)
+            .include_units(include_units);
+        self.translate_with(time, &options)
+    }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Language {
     English,
     Spanish,
     Portuguese,
+    French,
+    German,
+    Italian,
+    Japanese,
+    Russian,
+    Chinese,
 }
 
 impl Language {
+    /// Parses a language name or code, or a BCP 47 tag such as `pt-PT`.
     pub fn parse(s: &str) -> Option<Self> {
-        match s.to_lowercase().as_str() {
+        Self::parse_tag(s).map(|(language, _)| language)
+    }
+
+    /// Parses like `parse`, also returning the style picked by the tag's
+    /// region or script subtag, if any (`pt-PT` is European Portuguese,
+    /// `zh-Hant` traditional Chinese). Unknown subtags are ignored.
+    pub fn parse_tag(s: &str) -> Option<(Self, Option<Style>)> {
+        let tag = s.to_lowercase().replace('_', "-");
+        let mut subtags = tag.split('-');
+        let language = match subtags.next()? {
             "english" | "en" => Some(Language::English),
             "spanish" | "es" | "español" => Some(Language::Spanish),
             "portuguese" | "pt" | "português" => Some(Language::Portuguese),
+            "french" | "fr" | "français" => Some(Language::French),
+            "german" | "de" | "deutsch" => Some(Language::German),
+            "italian" | "it" | "italiano" => Some(Language::Italian),
+            "japanese" | "ja" | "日本語" => Some(Language::Japanese),
+            "russian" | "ru" | "русский" => Some(Language::Russian),
+            "chinese" | "zh" | "中文" => Some(Language::Chinese),
             _ => None,
+        }?;
+        let style = subtags.find_map(|subtag| language.subtag_style(subtag));
+        Some((language, style))
+    }
+
+    /// Parses a POSIX locale name such as `pt_BR.UTF-8` or `de_DE@euro`,
+    /// like `parse_tag`.
+    pub fn from_locale(locale: &str) -> Option<(Self, Option<Style>)> {
+        let tag = locale.split(['.', '@']).next()?;
+        Self::parse_tag(tag)
+    }
+
+    /// The language of the user's locale: the first of `LC_ALL`, `LC_TIME`
+    /// and `LANG` that is set and not empty, read with `from_locale`. `None`
+    /// if none is set or the locale is not a supported language (`C`).
+    pub fn from_env() -> Option<(Self, Option<Style>)> {
+        ["LC_ALL", "LC_TIME", "LANG"]
+            .into_iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|locale| Self::from_locale(&locale))
+    }
+
+    fn subtag_style(&self, subtag: &str) -> Option<Style> {
+        match (self, subtag) {
+            (Language::Portuguese, "br") => Some(Style::Brazilian),
+            (Language::Portuguese, "pt") => Some(Style::European),
+            (Language::Spanish, "es") => Some(Style::Castilian),
+            (Language::Spanish, "mx" | "419") => Some(Style::LatinAmerican),
+            (Language::Chinese, "hans" | "cn" | "sg") => Some(Style::Simplified),
+            (Language::Chinese, "hant" | "tw" | "hk" | "mo") => Some(Style::Traditional),
+            _ => None,
+        }
+    }
+
+    /// ISO 639-1 code of the language.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::Portuguese => "pt",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Italian => "it",
+            Language::Japanese => "ja",
+            Language::Russian => "ru",
+            Language::Chinese => "zh",
         }
     }
 }
@@ -56,5 +581,11 @@ pub fn get_translator(language: Language) -> Box<dyn TimeTranslator> {
         Language::English => Box::new(english::EnglishTranslator),
         Language::Spanish => Box::new(spanish::SpanishTranslator),
         Language::Portuguese => Box::new(portuguese::PortugueseTranslator),
+        Language::French => Box::new(french::FrenchTranslator),
+        Language::German => Box::new(german::GermanTranslator),
+        Language::Italian => Box::new(italian::ItalianTranslator),
+        Language::Japanese => Box::new(japanese::JapaneseTranslator),
+        Language::Russian => Box::new(russian::RussianTranslator),
+        Language::Chinese => Box::new(chinese::ChineseTranslator),
     }
 }
@@ -0,0 +1,1328 @@
+//! Data-driven translators.
+//!
+//! A `LanguagePack` describes a language declaratively (number words,
+//! gendered forms, unit labels, bucket phrases and period words) and a
+//! `PackTranslator` turns it into a `TimeTranslator`. The built-in languages
+//! are packs bundled from `src/translator/packs/`.
+//!
+//! Phrases are templates where `{name}` is replaced by a value computed for
+//! the time being translated:
+//!
+//! - `{hour}`, `{next_hour}`: hour words (12- or 24-hour, following the
+//!   options)
+//! - `{hour_name}`, `{next_hour_name}`: the hour's entry in `[hour-names]`
+//!   ("midi"), or else the hour word
+//! - `{o_clock}`: the `o-clock` word of `[units]`, left out after a named hour
+//!   ("three o'clock", but "noon")
+//! - `{minute}`: minute word
+//! - `{padded_minute}`: minute word, with the `minute-zero` word before 1-9
+//! - `{minutes_to}`: minutes left until the next hour ("twenty-five to")
+//! - `{hour_unit}`, `{next_hour_unit}`, `{minute_unit}`, `{minutes_to_unit}`:
+//!   unit labels, empty unless units are requested; the hour labels are also
+//!   left out when the template names that hour with `{hour_name}` or
+//!   `{next_hour_name}`
+//! - `{meridiem}`: the 12-hour suffix for the time, empty in 24-hour mode
+//! - `{article}`: the article the requested tense puts before the hour, if any
+//!
+//! The `[tense.past]` and `[tense.future]` (and optionally `[tense.present]`)
+//! sections wrap the bucket phrase: their `text` holds a `{phrase}`
+//! placeholder and, like `article`, may have plural forms that agree with the
+//! hour the phrase is about (`{next_hour}` when it is used, `{hour}`
+//! otherwise). Hours with a name in `[hour-names]` agree like one, and in
+//! place of the tense's article take the one given with the name, if any.
+//! Max-fuzzy period names are never wrapped; around a named hour the
+//! max-fuzzy level says its name instead. Turning `hour_names` off in the
+//! options says every hour with its number.
+//!
+//! The rounded level rounds the time to its step first and then uses the
+//! `[rounded]` phrase for the resulting minute: an entry of `[rounded.minutes]`
+//! if there is one, else `[rounded.to]` from its `from` minute on, else
+//! `past`. Packs without `[rounded]` say the rounded time with `[exact]`.
+//!
+//! Under the floor and ceiling rounding modes the fuzzy and very fuzzy levels
+//! are built the same way, from the time rounded to 15 or 30 minutes. When
+//! that is not the actual time, the `[approximate]` section qualifies the
+//! phrase: `almost` when the time is at most half a step short of it,
+//! `about` when it has passed it or is further off, each with a `{phrase}`
+//! placeholder. The very fuzzy level leaves out the meridiem. The optional
+//! `on-the-hour` replaces the minute 0 phrase inside a qualifier ("almost
+//! four" rather than "almost four o'clock sharp").
+//!
+//! The max-fuzzy level names the part of the day from `[day-periods.names]`,
+//! which has a word for every `DayPeriod`. Where the periods start comes
+//! from the options, else from the pack's `[day-periods.starts]` if it has
+//! one (Chinese splits the day more finely, Portuguese keeps the afternoon
+//! until seven), else `DayPeriods::default()`, unless the options have a
+//! location, in which case they follow the sun.
+//!
+//! The number placeholders (`{hour}`, `{next_hour}`, `{minute}`,
+//! `{padded_minute}`, `{minutes_to}`, and the hour word in `{hour_name}` and
+//! `{next_hour_name}`) can name a form from `[numbers.forms]` after a colon,
+//! e.g. `{hour:attributive}` for German "ein Uhr" next to "halb eins". An
+//! hour name takes the same form from its own `forms`, if it has it.
+//!
+//! Plural forms are picked by the CLDR category of the number under the
+//! pack's `plural` rule, so `{ one = "час", few = "часа", many = "часов" }`
+//! works for Russian as `{ one = "hour", other = "hours" }` does for English.
+//!
+//! A `[styles.<name>]` table is a partial pack merged over the rest of the
+//! file (tables key by key, arrays replaced whole) and used when that style is
+//! requested, e.g. German `[styles.southern]` with its own `fuzzy` buckets.
+//!
+//! Empty values leave no gaps: runs of spaces are collapsed and the result is
+//! trimmed.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::Deserialize;
+
+use super::calendar::{Hemisphere, Season, Third};
+use super::periods::{self, DayPeriods};
+use super::plural::{PluralCategory, PluralRule};
+use super::{
+    DayPeriod, FuzzinessLevel, RoundingMode, ShowDay, Style, Tense, TimeTranslator,
+    TranslateOptions,
+};
+use crate::time::TimeInfo;
+
+const PLACEHOLDERS: &[&str] = &[
+    "hour",
+    "next_hour",
+    "minute",
+    "padded_minute",
+    "hour_unit",
+    "next_hour_unit",
+    "minute_unit",
+    "minutes_to",
+    "minutes_to_unit",
+    "meridiem",
+    "article",
+    "hour_name",
+    "next_hour_name",
+    "o_clock",
+];
+
+/// Placeholders that accept a `:form` suffix.
+const NUMBER_PLACEHOLDERS: &[&str] = &[
+    "hour",
+    "next_hour",
+    "minute",
+    "padded_minute",
+    "minutes_to",
+    "hour_name",
+    "next_hour_name",
+];
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct LanguagePack {
+    pub language: PackInfo,
+    pub numbers: Numbers,
+    pub units: Units,
+    /// Names that replace an hour and its unit label in `{hour_name}`, by
+    /// 24-hour hour (e.g. `12 = "midi"`). The meridiem is left out for them.
+    #[serde(default)]
+    pub hour_names: BTreeMap<u32, HourName>,
+    /// 12-hour suffixes, each used from its `from` hour (0-23) until the next
+    /// one starts (wrapping around midnight).
+    #[serde(default)]
+    pub meridiem: Vec<Meridiem>,
+    pub exact: Phrase,
+    pub fuzzy: Vec<Bucket>,
+    pub very_fuzzy: Vec<Bucket>,
+    pub rounded: Option<Rounded>,
+    pub approximate: Option<Approximate>,
+    #[serde(default)]
+    pub tense: Tenses,
+    pub day_periods: PeriodNames,
+    pub weekdays: Option<CalendarNames>,
+    pub day: Option<DayPhrases>,
+    pub week: Option<WeekParts>,
+    pub months: Option<CalendarNames>,
+    pub month: Option<Thirds>,
+    pub seasons: Option<CalendarNames>,
+    pub season: Option<Thirds>,
+    /// The `[styles.<name>]` tables as written.
+    #[serde(default, rename = "styles")]
+    overlays: BTreeMap<Style, toml::Table>,
+    /// The pack for each style, with its overlay merged in.
+    #[serde(skip)]
+    pub styles: BTreeMap<Style, LanguagePack>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct PackInfo {
+    pub code: String,
+    pub name: String,
+    /// Name of the style the base phrasing follows, if it has one.
+    pub style: Option<Style>,
+    /// How numbers pick between `one`/`few`/`many`/... forms.
+    #[serde(default)]
+    pub plural: PluralRule,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Numbers {
+    /// Words for 0 up to `words.len() - 1`.
+    pub words: Vec<String>,
+    /// Words for multiples of ten, indexed by the tens digit. Numbers past
+    /// `words` are built as tens word + `tens-separator` + unit word.
+    pub tens: Vec<String>,
+    #[serde(default)]
+    pub tens_separator: String,
+    /// Word placed before single-digit minutes in `{padded_minute}`.
+    pub minute_zero: String,
+    /// Name of the form in `forms` used for hours, if any.
+    pub hour_form: Option<String>,
+    /// Name of the form in `forms` used for minutes, if any.
+    pub minute_form: Option<String>,
+    /// Gendered (or otherwise inflected) overrides, by form name then number.
+    /// Overrides of 1-9 also apply inside compound numbers.
+    #[serde(default)]
+    pub forms: BTreeMap<String, BTreeMap<u32, String>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Units {
+    pub hour: Plural,
+    pub minute: Plural,
+    /// Show the hour label even when units are not requested, for languages
+    /// where it is part of every time ("trois heures").
+    #[serde(default)]
+    pub hour_always: bool,
+    /// Word that marks a whole hour ("o'clock", "en punto"), as `{o_clock}`.
+    #[serde(default)]
+    pub o_clock: String,
+    /// Number form for minutes in phrases that show their unit label, when
+    /// units are requested ("un minuto", but "l'una e uno").
+    pub minute_form: Option<String>,
+}
+
+/// The name of an hour: either just the name, or a table with the `name`,
+/// optionally the `article` that `{article}` puts before it ("para a
+/// meia-noite") and `forms` of the name by number form ("около полуночи").
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum HourName {
+    Plain(String),
+    Table {
+        name: String,
+        article: Option<String>,
+        #[serde(default)]
+        forms: BTreeMap<String, String>,
+    },
+}
+
+impl HourName {
+    pub fn name(&self) -> &str {
+        match self {
+            HourName::Plain(name) | HourName::Table { name, .. } => name,
+        }
+    }
+
+    pub fn article(&self) -> Option<&str> {
+        match self {
+            HourName::Plain(_) => None,
+            HourName::Table { article, .. } => article.as_deref(),
+        }
+    }
+
+    /// The name in `form`, if it has one, else the name itself.
+    pub fn form(&self, form: Option<&str>) -> &str {
+        match (self, form) {
+            (HourName::Table { forms, .. }, Some(form)) => {
+                forms.get(form).map_or(self.name(), String::as_str)
+            }
+            _ => self.name(),
+        }
+    }
+}
+
+/// Text that may change with a number: either a single string or a table
+/// of forms by CLDR plural category (`zero`, `one`, `two`, `few`, `many`),
+/// with `other` used for any category left out.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Plural {
+    Invariant(String),
+    Forms {
+        zero: Option<String>,
+        one: Option<String>,
+        two: Option<String>,
+        few: Option<String>,
+        many: Option<String>,
+        other: String,
+    },
+}
+
+impl Plural {
+    fn select(&self, category: PluralCategory) -> &str {
+        match self {
+            Plural::Invariant(text) => text,
+            Plural::Forms {
+                zero,
+                one,
+                two,
+                few,
+                many,
+                other,
+            } => {
+                let form = match category {
+                    PluralCategory::Zero => zero,
+                    PluralCategory::One => one,
+                    PluralCategory::Two => two,
+                    PluralCategory::Few => few,
+                    PluralCategory::Many => many,
+                    PluralCategory::Other => &None,
+                };
+                form.as_deref().unwrap_or(other)
+            }
+        }
+    }
+
+    fn texts(&self) -> Vec<&str> {
+        match self {
+            Plural::Invariant(text) => vec![text],
+            Plural::Forms {
+                zero,
+                one,
+                two,
+                few,
+                many,
+                other,
+            } => [zero, one, two, few, many]
+                .into_iter()
+                .flatten()
+                .map(String::as_str)
+                .chain([other.as_str()])
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Tenses {
+    pub present: Option<TenseForms>,
+    pub past: Option<TenseForms>,
+    pub future: Option<TenseForms>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TenseForms {
+    /// Wrapper around the bucket phrase, with a `{phrase}` placeholder.
+    pub text: Plural,
+    /// Value of `{article}` in this tense.
+    pub article: Option<Plural>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Meridiem {
+    pub from: u32,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Phrase {
+    pub text: String,
+    /// Used instead of `text` at minute 0, if given.
+    pub on_the_hour: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Bucket {
+    /// First and last minute (inclusive) the phrase is used for.
+    pub minutes: [u32; 2],
+    /// The phrase, optionally with plural forms that agree with the hour it
+    /// is about ("l'una e mezza passata", "le tre e mezza passate").
+    pub text: Plural,
+}
+
+/// Phrases for the rounded level, picked by the rounded minute.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rounded {
+    /// Phrases for particular minutes ("quarter past"), by minute.
+    #[serde(default)]
+    pub minutes: BTreeMap<u32, String>,
+    /// Phrase for the other minutes before `to.from` ("ten past").
+    pub past: String,
+    /// Phrase counting down to the next hour ("ten to"), if the language
+    /// does that.
+    pub to: Option<CountDown>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CountDown {
+    /// First minute the phrase is used for.
+    pub from: u32,
+    pub text: String,
+}
+
+/// Qualifiers for a rounded phrase that is not the actual time.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Approximate {
+    /// Used when the time is past the rounded one ("about {phrase}").
+    pub about: String,
+    /// Used when the time is before the rounded one ("almost {phrase}").
+    pub almost: String,
+    /// Used instead of the minute 0 phrase inside a qualifier, if given.
+    pub on_the_hour: Option<String>,
+}
+
+/// Max-fuzzy period words.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PeriodNames {
+    /// A name for every `DayPeriod`, so that any `DayPeriods` can be said.
+    pub names: BTreeMap<DayPeriod, String>,
+    /// Where the periods start when the options do not say, for languages
+    /// that split the day differently from `DayPeriods::default()`.
+    pub starts: Option<DayPeriods>,
+}
+
+/// Weekday (Monday first), month (January first) or season (spring,
+/// summer, autumn, winter) names.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CalendarNames {
+    pub names: Vec<String>,
+    /// Inflected names for `{weekday:<form>}` and the like, by form name
+    /// ("во вторник").
+    #[serde(default)]
+    pub forms: BTreeMap<String, Vec<String>>,
+}
+
+impl CalendarNames {
+    /// The name at `index`, in `form` if there is one.
+    fn get(&self, index: usize, form: Option<&str>) -> &str {
+        let names = form
+            .and_then(|form| self.forms.get(form))
+            .unwrap_or(&self.names);
+        &names[index]
+    }
+}
+
+/// Phrases for the day level and for `ShowDay`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DayPhrases {
+    /// The weekday with its day period ("{weekday} {period}").
+    pub text: String,
+    /// Used instead of `text` for particular periods ("early {weekday}
+    /// morning").
+    #[serde(default)]
+    pub periods: BTreeMap<DayPeriod, String>,
+    /// Wraps the phrase when the period began the day before ("late
+    /// {phrase}"), if given.
+    pub late: Option<String>,
+    /// How `ShowDay` follows a phrase with its day ("{phrase}, {day}").
+    pub attach: String,
+    pub relative: RelativeDays,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RelativeDays {
+    pub yesterday: String,
+    pub today: String,
+    pub tomorrow: String,
+}
+
+/// Phrases for the week level.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WeekParts {
+    /// Monday and Tuesday.
+    pub start: String,
+    /// Wednesday and Thursday.
+    pub middle: String,
+    /// Friday.
+    pub end: String,
+    /// Saturday and Sunday.
+    pub weekend: String,
+}
+
+/// Phrases for the beginning, middle and end of a month ("early {month}")
+/// or season.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Thirds {
+    pub early: String,
+    pub middle: String,
+    pub late: String,
+}
+
+impl Thirds {
+    fn get(&self, third: Third) -> &str {
+        match third {
+            Third::Early => &self.early,
+            Third::Middle => &self.middle,
+            Third::Late => &self.late,
+        }
+    }
+}
+
+/// Error returned when a language pack cannot be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackError(pub String);
+
+impl fmt::Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid language pack: {}", self.0)
+    }
+}
+
+impl std::error::Error for PackError {}
+
+impl LanguagePack {
+    /// Parses and validates a pack written in TOML.
+    pub fn from_toml_str(text: &str) -> Result<Self, PackError> {
+        let mut pack: LanguagePack =
+            toml::from_str(text).map_err(|err| PackError(err.to_string()))?;
+        pack.validate()?;
+
+        if !pack.overlays.is_empty() {
+            let mut base: toml::Table =
+                toml::from_str(text).map_err(|err| PackError(err.to_string()))?;
+            base.remove("styles");
+            for (style, overlay) in std::mem::take(&mut pack.overlays) {
+                let mut merged = base.clone();
+                merge_tables(&mut merged, overlay);
+                let styled = toml::to_string(&merged)
+                    .map_err(|err| PackError(err.to_string()))
+                    .and_then(|text| Self::from_toml_str(&text))
+                    .map_err(|err| {
+                        PackError(format!("in style '{}': {}", style.as_str(), err.0))
+                    })?;
+                pack.styles.insert(style, styled);
+            }
+        }
+        Ok(pack)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, PackError> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| PackError(format!("{}: {}", path.display(), err)))?;
+        Self::from_toml_str(&text)
+    }
+
+    fn validate(&self) -> Result<(), PackError> {
+        let forms = [
+            &self.numbers.hour_form,
+            &self.numbers.minute_form,
+            &self.units.minute_form,
+        ];
+        for form in forms.into_iter().flatten() {
+            if !self.numbers.forms.contains_key(form) {
+                return Err(PackError(format!("unknown number form '{}'", form)));
+            }
+        }
+
+        if let Some(hour) = self.hour_names.keys().find(|&&hour| hour > 23) {
+            return Err(PackError(format!(
+                "hour-names keys must be hours between 0 and 23 (got {})",
+                hour
+            )));
+        }
+        for name in self.hour_names.values() {
+            if let HourName::Table { forms, .. } = name
+                && let Some(form) = forms
+                    .keys()
+                    .find(|form| !self.numbers.forms.contains_key(*form))
+            {
+                return Err(PackError(format!(
+                    "unknown number form '{}' in hour-names",
+                    form
+                )));
+            }
+        }
+
+        validate_buckets("fuzzy", &self.fuzzy)?;
+        validate_buckets("very-fuzzy", &self.very_fuzzy)?;
+        validate_starts("meridiem", self.meridiem.iter().map(|m| m.from))?;
+        if let Some(period) = DayPeriod::ALL
+            .into_iter()
+            .find(|period| !self.day_periods.names.contains_key(period))
+        {
+            return Err(PackError(format!(
+                "day-periods has no name for '{}'",
+                period.as_str()
+            )));
+        }
+
+        self.validate_calendar()?;
+
+        if let Some(rounded) = &self.rounded {
+            let mut minutes = rounded
+                .minutes
+                .keys()
+                .chain(rounded.to.iter().map(|to| &to.from));
+            if let Some(minute) = minutes.find(|&&minute| minute > 59) {
+                return Err(PackError(format!(
+                    "rounded minutes must be between 0 and 59 (got {})",
+                    minute
+                )));
+            }
+        }
+
+        let rounded = self.rounded.iter().flat_map(|rounded| {
+            std::iter::once(&rounded.past)
+                .chain(rounded.minutes.values())
+                .chain(rounded.to.iter().map(|to| &to.text))
+        });
+        let templates = std::iter::once(&self.exact.text)
+            .chain(&self.exact.on_the_hour)
+            .chain(rounded)
+            .chain(self.approximate.iter().flat_map(|a| &a.on_the_hour));
+        for template in templates {
+            self.validate_template(template)?;
+        }
+        for bucket in self.fuzzy.iter().chain(&self.very_fuzzy) {
+            for text in bucket.text.texts() {
+                self.validate_template(text)?;
+            }
+        }
+
+        if let Some(approximate) = &self.approximate {
+            for text in [&approximate.about, &approximate.almost] {
+                if !text.contains("{phrase}") {
+                    return Err(PackError(format!(
+                        "approximate text \"{}\" has no {{phrase}} placeholder",
+                        text
+                    )));
+                }
+                self.validate_template(&text.replace("{phrase}", ""))?;
+            }
+        }
+
+        let tenses = [&self.tense.present, &self.tense.past, &self.tense.future];
+        for forms in tenses.into_iter().flatten() {
+            for text in forms.text.texts() {
+                if !text.contains("{phrase}") {
+                    return Err(PackError(format!(
+                        "tense text \"{}\" has no {{phrase}} placeholder",
+                        text
+                    )));
+                }
+                self.validate_template(&text.replace("{phrase}", ""))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_calendar(&self) -> Result<(), PackError> {
+        let lists = [
+            (&self.weekdays, 7, "weekdays need seven names, Monday first"),
+            (&self.months, 12, "months need twelve names, January first"),
+            (
+                &self.seasons,
+                4,
+                "seasons need four names: spring, summer, autumn and winter",
+            ),
+        ];
+        for (names, count, message) in lists {
+            let Some(names) = names else {
+                continue;
+            };
+            let mut lists = std::iter::once(&names.names).chain(names.forms.values());
+            if lists.any(|names| names.len() != count) {
+                return Err(PackError(message.to_string()));
+            }
+        }
+
+        let parts = [
+            (&self.month, &self.months, "month"),
+            (&self.season, &self.seasons, "season"),
+        ];
+        for (thirds, names, key) in parts {
+            let Some(thirds) = thirds else {
+                continue;
+            };
+            let Some(names) = names else {
+                return Err(PackError(format!("the {} section needs {}s", key, key)));
+            };
+            for template in [&thirds.early, &thirds.middle, &thirds.late] {
+                validate_placeholders(template, |placeholder| match placeholder.split_once(':') {
+                    Some((name, form)) => name == key && names.forms.contains_key(form),
+                    None => placeholder == key,
+                })?;
+            }
+        }
+
+        let Some(day) = &self.day else {
+            return Ok(());
+        };
+        let Some(weekdays) = &self.weekdays else {
+            return Err(PackError("the day section needs weekdays".to_string()));
+        };
+        let templates = std::iter::once(&day.text).chain(day.periods.values());
+        for template in templates {
+            validate_placeholders(template, |placeholder| match placeholder.split_once(':') {
+                Some(("weekday", form)) => weekdays.forms.contains_key(form),
+                _ => ["weekday", "period"].contains(&placeholder),
+            })?;
+        }
+        let wrappers = std::iter::once((&day.attach, &["phrase", "day"][..]))
+            .chain(day.late.iter().map(|late| (late, &["phrase"][..])));
+        for (wrapper, allowed) in wrappers {
+            if !wrapper.contains("{phrase}") {
+                return Err(PackError(format!(
+                    "day text \"{}\" has no {{phrase}} placeholder",
+                    wrapper
+                )));
+            }
+            validate_placeholders(wrapper, |placeholder| allowed.contains(&placeholder))?;
+        }
+        Ok(())
+    }
+
+    fn validate_template(&self, template: &str) -> Result<(), PackError> {
+        validate_placeholders(template, |placeholder| match placeholder.split_once(':') {
+            Some((name, form)) => {
+                NUMBER_PLACEHOLDERS.contains(&name) && self.numbers.forms.contains_key(form)
+            }
+            None => PLACEHOLDERS.contains(&placeholder),
+        })
+    }
+}
+
+/// Checks that every `{placeholder}` in `template` is closed and `known`.
+fn validate_placeholders(template: &str, known: impl Fn(&str) -> bool) -> Result<(), PackError> {
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        let close = rest[open..]
+            .find('}')
+            .ok_or_else(|| PackError(format!("unclosed '{{' in \"{}\"", template)))?;
+        let placeholder = &rest[open + 1..open + close];
+        if !known(placeholder) {
+            return Err(PackError(format!(
+                "unknown placeholder {{{}}} in \"{}\"",
+                placeholder, template
+            )));
+        }
+        rest = &rest[open + close + 1..];
+    }
+    Ok(())
+}
+
+/// Merges `overlay` into `base`: tables are merged key by key, anything else
+/// (including arrays) is replaced.
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(overlay)) => {
+                merge_tables(base, overlay)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn validate_buckets(section: &str, buckets: &[Bucket]) -> Result<(), PackError> {
+    let mut next = 0;
+    for bucket in buckets {
+        let [first, last] = bucket.minutes;
+        if first != next || last < first || last > 59 {
+            return Err(PackError(format!(
+                "{} buckets must cover minutes 0-59 in order without gaps (problem at {}-{})",
+                section, first, last
+            )));
+        }
+        next = last + 1;
+    }
+    if next != 60 {
+        return Err(PackError(format!(
+            "{} buckets must cover minutes 0-59 (missing {}-59)",
+            section, next
+        )));
+    }
+    Ok(())
+}
+
+fn validate_starts(section: &str, starts: impl Iterator<Item = u32>) -> Result<(), PackError> {
+    let starts: Vec<u32> = starts.collect();
+    let ordered = starts.windows(2).all(|pair| pair[0] < pair[1]);
+    if !ordered || starts.iter().any(|&hour| hour > 23) {
+        return Err(PackError(format!(
+            "{} entries need increasing `from` hours between 0 and 23",
+            section
+        )));
+    }
+    Ok(())
+}
+
+/// Finds the entry in effect at `hour24` in a list ordered by start hour,
+/// where the last entry carries on past midnight.
+fn starting_at<T>(entries: &[T], from: impl Fn(&T) -> u32, hour24: u32) -> Option<&T> {
+    entries
+        .iter()
+        .rev()
+        .find(|entry| from(entry) <= hour24)
+        .or(entries.last())
+}
+
+/// Replaces each `{placeholder}` in `template` with `value(placeholder)`.
+fn fill(template: &str, value: impl Fn(&str) -> String) -> String {
+    let mut output = String::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        let close = open + rest[open..].find('}').unwrap_or(rest.len() - open);
+        output.push_str(&rest[..open]);
+        output.push_str(&value(&rest[open + 1..close]));
+        rest = rest.get(close + 1..).unwrap_or("");
+    }
+    output.push_str(rest);
+    tidy(&output)
+}
+
+/// Fills a template whose only placeholder is a name from `names`, in any
+/// of its forms ("{month}", "{season:of}").
+fn fill_name(template: &str, names: &CalendarNames, index: usize) -> String {
+    fill(template, |placeholder| {
+        let form = placeholder.split_once(':').map(|(_, form)| form);
+        names.get(index, form).to_string()
+    })
+}
+
+/// Collapses the runs of spaces left by empty values and trims the ends.
+fn tidy(text: &str) -> String {
+    text.split(' ')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A `TimeTranslator` driven by a `LanguagePack`.
+#[derive(Debug, Clone)]
+pub struct PackTranslator {
+    pack: LanguagePack,
+    styles: BTreeMap<Style, PackTranslator>,
+}
+
+impl PackTranslator {
+    pub fn new(pack: LanguagePack) -> Self {
+        let styles = pack
+            .styles
+            .iter()
+            .map(|(&style, styled)| (style, PackTranslator::new(styled.clone())))
+            .collect();
+        PackTranslator { pack, styles }
+    }
+
+    pub fn pack(&self) -> &LanguagePack {
+        &self.pack
+    }
+
+    fn number(&self, n: u32, form: Option<&str>) -> String {
+        let numbers = &self.pack.numbers;
+        let inflected = form
+            .and_then(|form| numbers.forms.get(form))
+            .and_then(|overrides| overrides.get(&n));
+        if let Some(word) = inflected {
+            return word.clone();
+        }
+        if let Some(word) = numbers.words.get(n as usize) {
+            return word.clone();
+        }
+
+        // Numbers without a word are written as digits; below ten there is
+        // nothing left to split into tens and units
+        if n < 10 {
+            return n.to_string();
+        }
+        match numbers.tens.get((n / 10) as usize) {
+            Some(tens) if n.is_multiple_of(10) => tens.clone(),
+            Some(tens) => format!(
+                "{}{}{}",
+                tens,
+                numbers.tens_separator,
+                self.number(n % 10, form)
+            ),
+            None => n.to_string(),
+        }
+    }
+
+    fn hour_word(&self, hour: u32, form: Option<&str>) -> String {
+        self.number(hour, form.or(self.pack.numbers.hour_form.as_deref()))
+    }
+
+    fn minute_word(&self, minute: u32, form: Option<&str>) -> String {
+        self.number(minute, form.or(self.pack.numbers.minute_form.as_deref()))
+    }
+
+    /// The form of `plural` that agrees with `n`.
+    fn agree<'a>(&self, plural: &'a Plural, n: u32) -> &'a str {
+        plural.select(self.pack.language.plural.category(n))
+    }
+
+    fn named_hour(&self, hour24: u32, options: &TranslateOptions) -> Option<&HourName> {
+        if !options.hour_names {
+            return None;
+        }
+        self.pack.hour_names.get(&hour24)
+    }
+
+    fn hour_name(&self, hour24: u32, options: &TranslateOptions) -> Option<&str> {
+        self.named_hour(hour24, options).map(HourName::name)
+    }
+
+    fn meridiem(&self, hour24: u32) -> &str {
+        starting_at(&self.pack.meridiem, |meridiem| meridiem.from, hour24)
+            .map_or("", |meridiem| meridiem.text.as_str())
+    }
+
+    fn tense(&self, tense: Tense) -> Option<&TenseForms> {
+        let tenses = &self.pack.tense;
+        match tense {
+            Tense::Present => tenses.present.as_ref(),
+            Tense::Past => tenses.past.as_ref(),
+            Tense::Future => tenses.future.as_ref(),
+        }
+    }
+
+    fn period_name(&self, time: &TimeInfo, options: &TranslateOptions) -> &str {
+        let period = self.day_period(time, options);
+        &self.pack.day_periods.names[&period]
+    }
+
+    /// The hour `template` is about, in the options' clock and as a 24-hour
+    /// hour, and whether it is said by name.
+    fn subject(
+        &self,
+        template: &str,
+        time: &TimeInfo,
+        options: &TranslateOptions,
+    ) -> (u32, u32, bool) {
+        let (subject, subject24) = match (template.contains("{next_hour"), options.use_24h) {
+            (true, true) => ((time.hour24() + 1) % 24, (time.hour24() + 1) % 24),
+            (true, false) => (time.hour() % 12 + 1, (time.hour24() + 1) % 24),
+            (false, true) => (time.hour24(), time.hour24()),
+            (false, false) => (time.hour(), time.hour24()),
+        };
+        let named = template.contains("hour_name") && self.hour_name(subject24, options).is_some();
+        (subject, subject24, named)
+    }
+
+    /// The number the phrase agrees with: named hours ("mezzogiorno") agree
+    /// like one.
+    fn agreement(&self, template: &str, time: &TimeInfo, options: &TranslateOptions) -> u32 {
+        match self.subject(template, time, options) {
+            (_, _, true) => 1,
+            (subject, _, false) => subject,
+        }
+    }
+
+    fn render(&self, template: &str, time: &TimeInfo, options: &TranslateOptions) -> String {
+        let (hour, next_hour) = if options.use_24h {
+            (time.hour24(), (time.hour24() + 1) % 24)
+        } else {
+            (time.hour(), time.hour() % 12 + 1)
+        };
+        // The hour the phrase is about, which the tense agrees with; named
+        // hours take their own article
+        let (subject, subject24, named) = self.subject(template, time, options);
+        let agreement = self.agreement(template, time, options);
+        let tense = self.tense(options.tense);
+        let template = match tense {
+            Some(forms) => self
+                .agree(&forms.text, agreement)
+                .replace("{phrase}", template),
+            None => template.to_string(),
+        };
+
+        let unit = |plural: &Plural, n: u32| {
+            if options.include_units {
+                self.agree(plural, n).to_string()
+            } else {
+                String::new()
+            }
+        };
+        let hour_unit = |n: u32| {
+            if self.pack.units.hour_always {
+                self.agree(&self.pack.units.hour, n).to_string()
+            } else {
+                unit(&self.pack.units.hour, n)
+            }
+        };
+        let hour_name = |n: u32, hour24: u32, form: Option<&str>| {
+            self.named_hour(hour24, options).map_or_else(
+                || self.hour_word(n, form),
+                |name| name.form(form).to_string(),
+            )
+        };
+        // A name stands for the hour and its unit label alike
+        let named_hour = |placeholder: &str, hour24: u32| {
+            template.contains(placeholder) && self.hour_name(hour24, options).is_some()
+        };
+
+        // Minutes counted in front of their label may take another form
+        let counted = |label: &str| {
+            self.pack
+                .units
+                .minute_form
+                .as_deref()
+                .filter(|_| options.include_units && template.contains(label))
+        };
+        let (minute_counted, minutes_to_counted) =
+            (counted("{minute_unit}"), counted("{minutes_to_unit}"));
+
+        fill(&template, |placeholder| {
+            let (name, form) = match placeholder.split_once(':') {
+                Some((name, form)) => (name, Some(form)),
+                None => (placeholder, None),
+            };
+            let minute_form = form.or(minute_counted);
+            match name {
+                "hour" => self.hour_word(hour, form),
+                "next_hour" => self.hour_word(next_hour, form),
+                "minute" => self.minute_word(time.minute(), minute_form),
+                "padded_minute" if time.minute() < 10 => format!(
+                    "{} {}",
+                    self.pack.numbers.minute_zero,
+                    self.minute_word(time.minute(), minute_form)
+                ),
+                "padded_minute" => self.minute_word(time.minute(), minute_form),
+                "hour_unit" if !named_hour("{hour_name", time.hour24()) => hour_unit(hour),
+                "next_hour_unit" if !named_hour("{next_hour_name", (time.hour24() + 1) % 24) => {
+                    hour_unit(next_hour)
+                }
+                "hour_name" => hour_name(hour, time.hour24(), form),
+                "next_hour_name" => hour_name(next_hour, (time.hour24() + 1) % 24, form),
+                "o_clock" if !named => self.pack.units.o_clock.clone(),
+                "minute_unit" => unit(&self.pack.units.minute, time.minute()),
+                "minutes_to" => self.minute_word(60 - time.minute(), form.or(minutes_to_counted)),
+                "minutes_to_unit" => unit(&self.pack.units.minute, 60 - time.minute()),
+                "meridiem" if !options.use_24h && !named => {
+                    self.meridiem(time.hour24()).to_string()
+                }
+                "article" => tense.and_then(|forms| forms.article.as_ref()).map_or(
+                    String::new(),
+                    |article| match self.named_hour(subject24, options) {
+                        Some(name) if named => name.article().unwrap_or_default().to_string(),
+                        _ => self.agree(article, subject).to_string(),
+                    },
+                ),
+                _ => String::new(),
+            }
+        })
+    }
+
+    fn exact_text(&self, minute: u32) -> &str {
+        match &self.pack.exact.on_the_hour {
+            Some(text) if minute == 0 => text,
+            _ => &self.pack.exact.text,
+        }
+    }
+
+    fn rounded_text(&self, minute: u32) -> &str {
+        let Some(rounded) = &self.pack.rounded else {
+            return self.exact_text(minute);
+        };
+        match (rounded.minutes.get(&minute), &rounded.to) {
+            (Some(text), _) => text,
+            (None, Some(to)) if minute >= to.from => &to.text,
+            _ => &rounded.past,
+        }
+    }
+
+    /// The fuzzy and very fuzzy levels under the floor and ceiling rounding
+    /// modes: the time rounded to the level's step, qualified by how far the
+    /// actual time is from it and on which side.
+    fn translate_approximate(&self, time: &TimeInfo, options: &TranslateOptions) -> Option<String> {
+        if options.rounding == RoundingMode::Nearest {
+            return None;
+        }
+        let step_minutes = match options.level {
+            FuzzinessLevel::Fuzzy | FuzzinessLevel::VeryFuzzy => options.level.step_minutes()?,
+            _ => return None,
+        };
+        let rounded = options.rounding.round(time, step_minutes);
+        let minute_of_day = |time: &TimeInfo| (time.hour24() * 60 + time.minute()) as i32;
+        // Minutes the actual time is past the rounded one, negative when it
+        // is still ahead
+        let offset = (minute_of_day(time) - minute_of_day(&rounded) + 720).rem_euclid(1440) - 720;
+
+        let phrase = self.rounded_text(rounded.minute());
+        let template = match &self.pack.approximate {
+            Some(approximate) if offset != 0 => {
+                // "Almost" only when the rounded time is close ahead
+                let qualifier = if offset < 0 && -offset * 2 <= step_minutes as i32 {
+                    &approximate.almost
+                } else {
+                    &approximate.about
+                };
+                let phrase = match &approximate.on_the_hour {
+                    Some(text) if rounded.minute() == 0 => text,
+                    _ => phrase,
+                };
+                qualifier.replace("{phrase}", phrase)
+            }
+            _ => phrase.to_string(),
+        };
+        // Very fuzzy phrases never say the meridiem
+        let template = match options.level {
+            FuzzinessLevel::VeryFuzzy => template.replace("{meridiem}", ""),
+            _ => template,
+        };
+        Some(self.render(&template, &rounded, options))
+    }
+
+    /// The max-fuzzy phrase: the name of the named hour the time rounds to,
+    /// if any, else the name of its day period.
+    fn period_text(&self, time: &TimeInfo, options: &TranslateOptions) -> String {
+        let step_minutes = FuzzinessLevel::MaxFuzzy.step_minutes().unwrap_or(60);
+        let rounded = options.rounding.round(time, step_minutes);
+        match self.hour_name(rounded.hour24(), options) {
+            Some(name) if rounded.minute() == 0 => name.to_string(),
+            _ => self.period_name(time, options).to_string(),
+        }
+    }
+
+    /// The date `time`'s day period belongs to, and whether that is the day
+    /// before because the period began before midnight ("late Sunday
+    /// night").
+    fn period_date(
+        &self,
+        time: &TimeInfo,
+        options: &TranslateOptions,
+    ) -> Option<(NaiveDate, bool)> {
+        let date = time.date()?;
+        let yesterday = date.pred_opt()?;
+        let last_night = TimeInfo::new(23, 59)
+            .ok()?
+            .on_date(yesterday, time.utc_offset()?);
+        let period = self.day_period(time, options);
+        let began_yesterday = time.hour24() < 12
+            && self.day_period(&time.with_time(0, 0).ok()?, options) == period
+            && self.day_period(&last_night, options) == period;
+        Some(if began_yesterday {
+            (yesterday, true)
+        } else {
+            (date, false)
+        })
+    }
+
+    fn weekday(&self, date: NaiveDate, form: Option<&str>) -> &str {
+        self.pack.weekdays.as_ref().map_or("", |weekdays| {
+            weekdays.get(date.weekday().num_days_from_monday() as usize, form)
+        })
+    }
+
+    /// The day-level phrase, if the pack has one and the time carries its
+    /// date.
+    fn day_text(&self, time: &TimeInfo, options: &TranslateOptions) -> Option<String> {
+        let day = self.pack.day.as_ref()?;
+        let (date, began_yesterday) = self.period_date(time, options)?;
+        let period = self.day_period(time, options);
+        let template = day.periods.get(&period).unwrap_or(&day.text);
+        let phrase = fill(template, |placeholder| match placeholder.split_once(':') {
+            Some((_, form)) => self.weekday(date, Some(form)).to_string(),
+            None if placeholder == "weekday" => self.weekday(date, None).to_string(),
+            None => self.pack.day_periods.names[&period].clone(),
+        });
+        Some(match &day.late {
+            Some(late) if began_yesterday => tidy(&late.replace("{phrase}", &phrase)),
+            _ => phrase,
+        })
+    }
+
+    /// The week-level phrase, if the pack has one and the time carries its
+    /// date.
+    fn week_text(&self, time: &TimeInfo, options: &TranslateOptions) -> Option<String> {
+        let week = self.pack.week.as_ref()?;
+        let (date, _) = self.period_date(time, options)?;
+        let text = match date.weekday() {
+            Weekday::Mon | Weekday::Tue => &week.start,
+            Weekday::Wed | Weekday::Thu => &week.middle,
+            Weekday::Fri => &week.end,
+            Weekday::Sat | Weekday::Sun => &week.weekend,
+        };
+        Some(text.clone())
+    }
+
+    /// The month-level phrase, if the pack has one and the time carries its
+    /// date.
+    fn month_text(&self, time: &TimeInfo) -> Option<String> {
+        let (month, months) = (self.pack.month.as_ref()?, self.pack.months.as_ref()?);
+        let date = time.date()?;
+        let template = month.get(Third::of_month(date));
+        Some(fill_name(template, months, date.month0() as usize))
+    }
+
+    /// The season-level phrase, if the pack has one and the time carries
+    /// its date.
+    fn season_text(&self, time: &TimeInfo, options: &TranslateOptions) -> Option<String> {
+        let (season, seasons) = (self.pack.season.as_ref()?, self.pack.seasons.as_ref()?);
+        let (which, third) = Season::of(time.date()?, Hemisphere::of(options));
+        Some(fill_name(season.get(third), seasons, which.index()))
+    }
+
+    /// The phrase for a date level, if the pack has one and the time
+    /// carries its date.
+    fn date_text(&self, time: &TimeInfo, options: &TranslateOptions) -> Option<String> {
+        match options.level {
+            FuzzinessLevel::Day => self.day_text(time, options),
+            FuzzinessLevel::Week => self.week_text(time, options),
+            FuzzinessLevel::Month => self.month_text(time),
+            FuzzinessLevel::Season => self.season_text(time, options),
+            _ => None,
+        }
+    }
+
+    /// Follows `phrase` with the day `time` is on, as `options.show_day`
+    /// asks.
+    fn attach_day(&self, phrase: String, time: &TimeInfo, options: &TranslateOptions) -> String {
+        let (Some(day), Some(date)) = (&self.pack.day, time.date()) else {
+            return phrase;
+        };
+        let label = match options.show_day {
+            ShowDay::Off => return phrase,
+            ShowDay::Weekday => self.weekday(date, None),
+            ShowDay::Relative => match options.today.map(|today| (date - today).num_days()) {
+                Some(-1) => &day.relative.yesterday,
+                Some(0) => &day.relative.today,
+                Some(1) => &day.relative.tomorrow,
+                _ => self.weekday(date, None),
+            },
+        };
+        tidy(
+            &day.attach
+                .replace("{day}", label)
+                .replace("{phrase}", &phrase),
+        )
+    }
+
+    fn phrase(&self, time: &TimeInfo, options: &TranslateOptions) -> String {
+        if let Some(phrase) = self.translate_approximate(time, options) {
+            return phrase;
+        }
+
+        let template = match options.level {
+            FuzzinessLevel::Exact => self.exact_text(time.minute()),
+            FuzzinessLevel::Fuzzy => self.bucket_text(&self.pack.fuzzy, time, options),
+            FuzzinessLevel::VeryFuzzy => self.bucket_text(&self.pack.very_fuzzy, time, options),
+            FuzzinessLevel::MaxFuzzy => return self.period_text(time, options),
+            FuzzinessLevel::Rounded { step_minutes } => {
+                let rounded = options.rounding.round(time, step_minutes);
+                return self.render(self.rounded_text(rounded.minute()), &rounded, options);
+            }
+            // Times without a date fall back to the day period alone
+            FuzzinessLevel::Day
+            | FuzzinessLevel::Week
+            | FuzzinessLevel::Month
+            | FuzzinessLevel::Season => {
+                return self
+                    .date_text(time, options)
+                    .unwrap_or_else(|| self.period_text(time, options));
+            }
+        };
+        self.render(template, time, options)
+    }
+
+    /// The phrase of the bucket `time` falls in, in the form that agrees
+    /// with its hour.
+    fn bucket_text<'a>(
+        &self,
+        buckets: &'a [Bucket],
+        time: &TimeInfo,
+        options: &TranslateOptions,
+    ) -> &'a str {
+        let minute = time.minute();
+        buckets
+            .iter()
+            .find(|bucket| (bucket.minutes[0]..=bucket.minutes[1]).contains(&minute))
+            .map_or("", |bucket| {
+                // Every form is about the same hour, so any of them tells which
+                let other = bucket.text.select(PluralCategory::Other);
+                self.agree(&bucket.text, self.agreement(other, time, options))
+            })
+    }
+}
+
+impl TimeTranslator for PackTranslator {
+    fn translate_with(&self, time: &TimeInfo, options: &TranslateOptions) -> String {
+        if let Some(styled) = options.style.and_then(|style| self.styles.get(&style)) {
+            return styled.translate_with(time, &options.style(None));
+        }
+        let phrase = self.phrase(time, options);
+        if options.level.is_date_level() {
+            phrase
+        } else {
+            self.attach_day(phrase, time, options)
+        }
+    }
+
+    fn day_period(&self, time: &TimeInfo, options: &TranslateOptions) -> DayPeriod {
+        if let Some(styled) = options.style.and_then(|style| self.styles.get(&style)) {
+            return styled.day_period(time, &options.style(None));
+        }
+        periods::day_period(time, options, self.pack.day_periods.starts)
+    }
+
+    fn supports_style(&self, style: Style) -> bool {
+        self.pack.language.style == Some(style) || self.styles.contains_key(&style)
+    }
+}
+
+/// Declares a unit struct translator for a pack bundled with the crate. The
+/// pack is parsed once, on first use.
+macro_rules! bundled_pack {
+    ($(#[$meta:meta])* $name:ident, $path:literal) => {
+        $(#[$meta])*
+        pub struct $name;
+
+        impl $name {
+            /// The bundled pack this translator is driven by.
+            pub fn pack() -> &'static $crate::translator::pack::PackTranslator {
+                static PACK: std::sync::LazyLock<$crate::translator::pack::PackTranslator> =
+                    std::sync::LazyLock::new(|| {
+                        let pack =
+                            $crate::translator::pack::LanguagePack::from_toml_str(include_str!($path))
+                                .expect(concat!("bundled language pack ", $path, " is valid"));
+                        $crate::translator::pack::PackTranslator::new(pack)
+                    });
+                &PACK
+            }
+        }
+
+        impl $crate::translator::TimeTranslator for $name {
+            fn translate_with(
+                &self,
+                time: &$crate::time::TimeInfo,
+                options: &$crate::translator::TranslateOptions,
+            ) -> String {
+                Self::pack().translate_with(time, options)
+            }
+
+            fn day_period(
+                &self,
+                time: &$crate::time::TimeInfo,
+                options: &$crate::translator::TranslateOptions,
+            ) -> $crate::translator::DayPeriod {
+                Self::pack().day_period(time, options)
+            }
+
+            fn supports_style(&self, style: $crate::translator::Style) -> bool {
+                Self::pack().supports_style(style)
+            }
+        }
+    };
+}
+
+pub(crate) use bundled_pack;
@@ -0,0 +1,160 @@
+//! Where the parts of the day named by the max-fuzzy level begin.
+//!
+//! The boundaries are shared by every language so that "evening" starts at
+//! the same hour whichever language says it; packs only give the names.
+//! Given a location, the periods follow the sun instead of the clock.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::Deserialize;
+
+use super::{DayPeriod, TranslateOptions};
+use crate::time::TimeInfo;
+use crate::time::sun::{Coordinates, SunPosition};
+
+/// Start hour (0-23) of each period in use. A period lasts until the next
+/// one starts, and the latest one carries on past midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "BTreeMap<DayPeriod, u32>")]
+pub struct DayPeriods {
+    starts: [Option<u32>; DayPeriod::ALL.len()],
+}
+
+impl Default for DayPeriods {
+    /// Morning from 5, afternoon from 12, evening from 17 and night from 22.
+    fn default() -> Self {
+        DayPeriods::from_starts([
+            (DayPeriod::Morning, 5),
+            (DayPeriod::Afternoon, 12),
+            (DayPeriod::Evening, 17),
+            (DayPeriod::Night, 22),
+        ])
+        .expect("default day periods are valid")
+    }
+}
+
+impl DayPeriods {
+    /// Builds the periods from their start hours. `None` if there are none,
+    /// an hour is past 23, or a period or hour is given twice.
+    pub fn from_starts(starts: impl IntoIterator<Item = (DayPeriod, u32)>) -> Option<Self> {
+        let mut periods = DayPeriods {
+            starts: [None; DayPeriod::ALL.len()],
+        };
+        for (period, hour) in starts {
+            let taken = periods.starts[period.index()].is_some();
+            if taken || hour > 23 || periods.starts.contains(&Some(hour)) {
+                return None;
+            }
+            periods.starts[period.index()] = Some(hour);
+        }
+        periods
+            .starts
+            .iter()
+            .any(Option::is_some)
+            .then_some(periods)
+    }
+
+    /// Parses a comma-separated list of `period=hour` pairs, e.g.
+    /// `"morning=5,afternoon=12,evening=17,night=22"`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let starts = s
+            .split(',')
+            .map(|pair| {
+                let (period, hour) = pair.split_once('=')?;
+                Some((DayPeriod::parse(period.trim())?, hour.trim().parse().ok()?))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        DayPeriods::from_starts(starts)
+    }
+
+    /// The hour `period` starts at, if it is in use.
+    pub fn start(&self, period: DayPeriod) -> Option<u32> {
+        self.starts[period.index()]
+    }
+
+    /// The periods in use with their start hours, earliest first.
+    pub fn iter(&self) -> impl Iterator<Item = (DayPeriod, u32)> + '_ {
+        let mut starts: Vec<_> = DayPeriod::ALL
+            .into_iter()
+            .filter_map(|period| Some((period, self.start(period)?)))
+            .collect();
+        starts.sort_by_key(|&(_, hour)| hour);
+        starts.into_iter()
+    }
+
+    /// The period in effect at `hour24`.
+    pub fn period_at(&self, hour24: u32) -> DayPeriod {
+        let starts: Vec<_> = self.iter().collect();
+        starts
+            .iter()
+            .rev()
+            .find(|&&(_, hour)| hour <= hour24)
+            .or(starts.last())
+            .map(|&(period, _)| period)
+            .expect("day periods are never empty")
+    }
+}
+
+/// Formatted the way `parse` reads it, earliest period first.
+impl fmt::Display for DayPeriods {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (period, hour)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}={}", period.as_str(), hour)?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<BTreeMap<DayPeriod, u32>> for DayPeriods {
+    type Error = String;
+
+    fn try_from(starts: BTreeMap<DayPeriod, u32>) -> Result<Self, Self::Error> {
+        DayPeriods::from_starts(starts).ok_or_else(|| {
+            "day periods need at least one period and different start hours between 0 and 23"
+                .to_string()
+        })
+    }
+}
+
+/// The period `time` falls in under `options`: by the sun when they have a
+/// location and the time carries its date, else by the options' hours, else
+/// by `language_periods` or the default hours.
+pub fn day_period(
+    time: &TimeInfo,
+    options: &TranslateOptions,
+    language_periods: Option<DayPeriods>,
+) -> DayPeriod {
+    options
+        .location
+        .and_then(|location| sun_period(location, time))
+        .unwrap_or_else(|| {
+            options
+                .day_periods
+                .or(language_periods)
+                .unwrap_or_default()
+                .period_at(time.hour24())
+        })
+}
+
+/// The sun-aware period at `location`, if `time` carries its date. Dawn and
+/// dusk are the civil twilights around sunrise and sunset, morning and
+/// afternoon the daylight before and after solar noon, and evening and night
+/// the dark before and after solar midnight.
+pub fn sun_period(location: Coordinates, time: &TimeInfo) -> Option<DayPeriod> {
+    let sun = SunPosition::at(location, time.to_utc()?);
+    let before_noon = sun.hour_angle < 0.0;
+    let period = match (sun.elevation >= SunPosition::HORIZON, sun.in_twilight()) {
+        (true, _) if before_noon => DayPeriod::Morning,
+        (true, _) => DayPeriod::Afternoon,
+        (false, true) if !sun.has_dawn_and_dusk() => DayPeriod::Twilight,
+        (false, true) if before_noon => DayPeriod::Dawn,
+        (false, true) => DayPeriod::Dusk,
+        (false, false) if before_noon => DayPeriod::Night,
+        (false, false) => DayPeriod::Evening,
+    };
+    Some(period)
+}
@@ -0,0 +1,48 @@
+//! CLDR plural categories for whole numbers.
+//!
+//! Languages pick a word form by the category of a number rather than by
+//! checking for 1: Russian says "час" (1, 21), "часа" (2-4, 22-24) and
+//! "часов" (0, 5-20).
+
+use serde::Deserialize;
+
+/// A CLDR plural category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// How a language maps whole numbers to plural categories.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PluralRule {
+    /// `one` for 1, `other` for the rest (English, German, Spanish).
+    #[default]
+    One,
+    /// `one` for 0 and 1 (French).
+    ZeroAndOne,
+    /// `one`, `few` and `many` by the last two digits (Russian, Ukrainian).
+    EastSlavic,
+    /// Always `other` (Japanese, Chinese).
+    None,
+}
+
+impl PluralRule {
+    pub fn category(&self, n: u32) -> PluralCategory {
+        match self {
+            PluralRule::One if n == 1 => PluralCategory::One,
+            PluralRule::ZeroAndOne if n <= 1 => PluralCategory::One,
+            PluralRule::EastSlavic => match (n % 10, n % 100) {
+                (1, rest) if rest != 11 => PluralCategory::One,
+                (2..=4, rest) if !(12..=14).contains(&rest) => PluralCategory::Few,
+                _ => PluralCategory::Many,
+            },
+            _ => PluralCategory::Other,
+        }
+    }
+}
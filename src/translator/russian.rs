@@ -0,0 +1,7 @@
+use super::pack::bundled_pack;
+
+bundled_pack!(
+    /// Russian, driven by `packs/russian.toml`.
+    RussianTranslator,
+    "packs/russian.toml"
+);
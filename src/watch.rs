@@ -0,0 +1,50 @@
+use std::io::{self, Write};
+use std::iter;
+use std::thread;
+
+use crate::reading::FuzzyReading;
+use crate::time;
+
+/// Keeps reading the clock once per minute and writes a line to `out` only
+/// when it differs from the previous one, so that formats carrying more than
+/// the phrase (the exact time in a tooltip, the minute in JSON) stay current.
+///
+/// Sleeps until each minute boundary in between and flushes after every line
+/// so that pipes and status bars update immediately. Only returns when
+/// writing fails, e.g. because the reading end of a pipe was closed.
+pub fn run<R, F, W>(read: R, format: F, out: &mut W) -> io::Result<()>
+where
+    R: FnMut() -> FuzzyReading,
+    F: Fn(&FuzzyReading) -> String,
+    W: Write,
+{
+    let ticks = iter::repeat_with(|| thread::sleep(time::until_next_minute()));
+    run_with_ticks(read, format, out, ticks)
+}
+
+/// Like `run`, but reads the clock once up front and again after each item of
+/// `ticks` rather than at every minute boundary, and returns when they run
+/// out.
+pub fn run_with_ticks<R, F, W, T>(mut read: R, format: F, out: &mut W, ticks: T) -> io::Result<()>
+where
+    R: FnMut() -> FuzzyReading,
+    F: Fn(&FuzzyReading) -> String,
+    W: Write,
+    T: IntoIterator,
+{
+    let mut last: Option<String> = None;
+    let mut ticks = ticks.into_iter();
+
+    loop {
+        let line = format(&read());
+        if last.as_ref() != Some(&line) {
+            writeln!(out, "{}", line)?;
+            out.flush()?;
+            last = Some(line);
+        }
+
+        if ticks.next().is_none() {
+            return Ok(());
+        }
+    }
+}
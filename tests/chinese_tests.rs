@@ -0,0 +1,250 @@
+use chrono::NaiveDate;
+use rust_fuzzy_clock::time::{TimeInfo, parse_time};
+use rust_fuzzy_clock::translator::{
+    DayPeriod, FuzzinessLevel, ShowDay, Style, Tense, TimeTranslator, TranslateOptions,
+    chinese::ChineseTranslator, periods::DayPeriods,
+};
+
+fn create_time_info(hour24: u32, minute: u32) -> TimeInfo {
+    TimeInfo::new(hour24, minute).unwrap()
+}
+
+#[test]
+fn test_exact_12h_format() {
+    let translator = ChineseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Exact);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "下午三点四十七分"
+    );
+
+    let time = create_time_info(9, 0);
+    assert_eq!(translator.translate_with(&time, &options), "上午九点");
+}
+
+#[test]
+fn test_exact_24h_format() {
+    let translator = ChineseTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .use_24h(true);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(translator.translate_with(&time, &options), "十五点四十七分");
+
+    let time = create_time_info(22, 2);
+    assert_eq!(translator.translate_with(&time, &options), "二十二点二分");
+}
+
+#[test]
+fn test_two_oclock_is_liang() {
+    let translator = ChineseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(2, 0);
+    assert_eq!(translator.translate_with(&time, &options), "两点整");
+
+    let time = create_time_info(12, 30);
+    assert_eq!(translator.translate_with(&time, &options), "中午十二点半");
+}
+
+#[test]
+fn test_fuzzy() {
+    let translator = ChineseTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .use_24h(true);
+
+    let time = create_time_info(3, 15);
+    assert_eq!(translator.translate_with(&time, &options), "三点一刻");
+
+    let time = create_time_info(3, 30);
+    assert_eq!(translator.translate_with(&time, &options), "三点半");
+
+    let time = create_time_info(3, 45);
+    assert_eq!(translator.translate_with(&time, &options), "差一刻四点");
+
+    let time = create_time_info(3, 57);
+    assert_eq!(translator.translate_with(&time, &options), "快四点了");
+}
+
+#[test]
+fn test_periods_before_12h_times() {
+    let translator = ChineseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let cases = [
+        (3, "凌晨三点半"),
+        (6, "早上六点半"),
+        (10, "上午十点半"),
+        (12, "中午十二点半"),
+        (15, "下午三点半"),
+        (20, "晚上八点半"),
+    ];
+    for (hour, expected) in cases {
+        let time = create_time_info(hour, 30);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+}
+
+#[test]
+fn test_very_fuzzy() {
+    let translator = ChineseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy);
+
+    let time = create_time_info(15, 20);
+    assert_eq!(translator.translate_with(&time, &options), "三点一刻左右");
+
+    let time = create_time_info(15, 40);
+    assert_eq!(translator.translate_with(&time, &options), "大约差一刻四点");
+}
+
+#[test]
+fn test_max_fuzzy_periods() {
+    let translator = ChineseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy);
+
+    let cases = [
+        (2, "凌晨", DayPeriod::EarlyMorning),
+        (6, "早上", DayPeriod::Morning),
+        (9, "上午", DayPeriod::LateMorning),
+        (12, "中午", DayPeriod::Noon),
+        (15, "下午", DayPeriod::Afternoon),
+        (21, "晚上", DayPeriod::Evening),
+    ];
+    for (hour, name, period) in cases {
+        let time = create_time_info(hour, 0);
+        assert_eq!(translator.translate_with(&time, &options), name);
+        assert_eq!(translator.day_period(&time, &options), period);
+    }
+}
+
+#[test]
+fn test_day_periods_override_pack_starts() {
+    let translator = ChineseTranslator;
+    let periods = DayPeriods::parse("morning=5,afternoon=12,evening=17,night=22");
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::MaxFuzzy)
+        .day_periods(periods);
+
+    let cases = [(2, "深夜"), (9, "早上"), (13, "下午"), (23, "深夜")];
+    for (hour, expected) in cases {
+        let time = create_time_info(hour, 0);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+}
+
+#[test]
+fn test_traditional_style() {
+    let translator = ChineseTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .style(Some(Style::Traditional));
+
+    let time = create_time_info(15, 30);
+    assert_eq!(translator.translate_with(&time, &options), "下午三點半");
+
+    let time = create_time_info(2, 0);
+    assert_eq!(translator.translate_with(&time, &options), "兩點整");
+
+    let time = create_time_info(15, 40);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::VeryFuzzy)),
+        "大約差一刻四點"
+    );
+
+    // Simplified is the default
+    assert_eq!(
+        translator.translate_with(&time, &options.style(Some(Style::Simplified))),
+        translator.translate_with(&time, &options.style(None))
+    );
+}
+
+#[test]
+fn test_supported_styles() {
+    let translator = ChineseTranslator;
+    assert!(translator.supports_style(Style::Simplified));
+    assert!(translator.supports_style(Style::Traditional));
+    assert!(!translator.supports_style(Style::Kanji));
+}
+
+#[test]
+fn test_tense_and_units_have_no_effect() {
+    let translator = ChineseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(15, 30);
+    let plain = translator.translate_with(&time, &options);
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Past)),
+        plain
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.include_units(true)),
+        plain
+    );
+}
+
+#[test]
+fn test_noon_and_midnight() {
+    let translator = ChineseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let cases = [
+        (12, 0, "中午十二点整"),
+        (11, 45, "差一刻中午十二点"),
+        (23, 55, "快午夜十二点了"),
+        (0, 0, "午夜十二点整"),
+    ];
+    for (hour, minute, expected) in cases {
+        let time = create_time_info(hour, minute);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+
+    let time = create_time_info(12, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options.hour_names(false)),
+        "十二点整"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.style(Some(Style::Traditional))),
+        "中午十二點整"
+    );
+
+    let time = create_time_info(23, 55);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::MaxFuzzy)),
+        "午夜"
+    );
+}
+
+#[test]
+fn test_day_and_week_levels() {
+    let translator = ChineseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Day);
+
+    // 凌晨 starts at midnight, so it is never the day before
+    let time = parse_time("2024-06-10T02:00:00+08:00").unwrap();
+    assert_eq!(translator.translate_with(&time, &options), "星期一凌晨");
+
+    let time = parse_time("2024-06-14T15:00:00+08:00").unwrap();
+    let options = options.level(FuzzinessLevel::Week);
+    assert_eq!(translator.translate_with(&time, &options), "临近周末");
+    assert_eq!(
+        translator.translate_with(&time, &options.style(Some(Style::Traditional))),
+        "臨近週末"
+    );
+}
+
+#[test]
+fn test_show_day_comes_first() {
+    let translator = ChineseTranslator;
+    let time = parse_time("2024-06-14T15:00:00+08:00").unwrap();
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::MaxFuzzy)
+        .show_day(ShowDay::Relative)
+        .today(NaiveDate::from_ymd_opt(2024, 6, 13));
+    assert_eq!(translator.translate_with(&time, &options), "明天下午");
+}
@@ -0,0 +1,116 @@
+use std::path::PathBuf;
+
+use rust_fuzzy_clock::cli::parse_args_from;
+use rust_fuzzy_clock::config::{Config, ConfigError};
+
+// Writes `contents` to a config file unique to the calling test
+fn write_config(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "rust-fuzzy-clock-{}-{}.toml",
+        name,
+        std::process::id()
+    ));
+    std::fs::write(&path, contents).expect("Failed to write config file");
+    path
+}
+
+#[test]
+fn test_parse_full_config() {
+    let config = Config::from_toml_str(
+        r#"
+language = "pt"
+fuzziness = "fuzzy"
+rounding = "floor"
+24-hour = true
+include-units = false
+day-periods = "morning=6,afternoon=12,evening=18,night=23"
+latitude = -23.55
+longitude = -46.63
+hemisphere = "south"
+show-day = "relative"
+timezone = "America/Sao_Paulo"
+watch = true
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.language.as_deref(), Some("pt"));
+    assert_eq!(config.fuzziness.as_deref(), Some("fuzzy"));
+    assert_eq!(config.rounding.as_deref(), Some("floor"));
+    assert_eq!(config.hour_24, Some(true));
+    assert_eq!(config.include_units, Some(false));
+    assert_eq!(
+        config.day_periods.as_deref(),
+        Some("morning=6,afternoon=12,evening=18,night=23")
+    );
+    assert_eq!(config.latitude, Some(-23.55));
+    assert_eq!(config.longitude, Some(-46.63));
+    assert_eq!(config.hemisphere.as_deref(), Some("south"));
+    assert_eq!(config.show_day.as_deref(), Some("relative"));
+    assert_eq!(config.timezone.as_deref(), Some("America/Sao_Paulo"));
+    assert_eq!(config.watch, Some(true));
+}
+
+#[test]
+fn test_empty_config() {
+    assert_eq!(Config::from_toml_str("").unwrap(), Config::default());
+}
+
+#[test]
+fn test_unknown_key_reports_line() {
+    let err = Config::from_toml_str("language = \"es\"\n\ncolour = \"red\"\n").unwrap_err();
+    assert!(err.contains("line 3"), "{}", err);
+    assert!(err.contains("colour"), "{}", err);
+}
+
+#[test]
+fn test_wrong_type_reports_line() {
+    let err = Config::from_toml_str("24-hour = \"yes\"\n").unwrap_err();
+    assert!(err.contains("line 1"), "{}", err);
+}
+
+#[test]
+fn test_missing_explicit_config_file() {
+    let result = parse_args_from(["rust-fuzzy-clock", "--config", "/nonexistent/config.toml"]);
+    assert!(matches!(result, Err(ConfigError::Io { .. })));
+}
+
+#[test]
+fn test_config_fills_unset_flags() {
+    let path = write_config(
+        "fills",
+        "language = \"pt\"\nfuzziness = \"fuzzy\"\n24-hour = true\n",
+    );
+    let args = parse_args_from(["rust-fuzzy-clock", "--config", path.to_str().unwrap()]).unwrap();
+
+    assert_eq!(args.language, "pt");
+    assert_eq!(args.fuzziness, "fuzzy");
+    assert!(args.hour_24);
+    assert!(!args.include_units);
+}
+
+#[test]
+fn test_cli_flags_override_config() {
+    let path = write_config("override", "language = \"pt\"\nfuzziness = \"fuzzy\"\n");
+    let args = parse_args_from([
+        "rust-fuzzy-clock",
+        "--config",
+        path.to_str().unwrap(),
+        "-l",
+        "es",
+    ])
+    .unwrap();
+
+    assert_eq!(args.language, "es");
+    assert_eq!(args.fuzziness, "fuzzy");
+}
+
+#[test]
+fn test_config_round_trip() {
+    let path = write_config("round-trip", "language = \"es\"\ninclude-units = true\n");
+    let args = parse_args_from(["rust-fuzzy-clock", "--config", path.to_str().unwrap()]).unwrap();
+
+    let printed = args.to_config().to_toml_string();
+    let reparsed = Config::from_toml_str(&printed).unwrap();
+    assert_eq!(reparsed, args.to_config());
+}
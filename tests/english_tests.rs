@@ -1,20 +1,14 @@
-use rust_fuzzy_clock::time::TimeInfo;
-use rust_fuzzy_clock::translator::{FuzzinessLevel, TimeTranslator, english::EnglishTranslator};
+use chrono::NaiveDate;
+use rust_fuzzy_clock::time::sun::Coordinates;
+use rust_fuzzy_clock::time::{TimeInfo, parse_time};
+use rust_fuzzy_clock::translator::calendar::Hemisphere;
+use rust_fuzzy_clock::translator::{
+    DayPeriod, FuzzinessLevel, RoundingMode, ShowDay, Tense, TimeTranslator, TranslateOptions,
+    english::EnglishTranslator, periods::DayPeriods,
+};
 
 fn create_time_info(hour24: u32, minute: u32) -> TimeInfo {
-    let is_pm = hour24 >= 12;
-    let hour12 = match hour24 {
-        0 => 12,
-        13..=23 => hour24 - 12,
-        _ => hour24,
-    };
-
-    TimeInfo {
-        hour: hour12,
-        hour24,
-        minute,
-        is_pm,
-    }
+    TimeInfo::new(hour24, minute).unwrap()
 }
 
 #[test]
@@ -23,19 +17,19 @@ fn test_exact_12h_format() {
 
     let time = create_time_info(15, 47);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "three forty-seven PM"
     );
 
     let time = create_time_info(9, 5);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "nine oh five AM"
     );
 
     let time = create_time_info(0, 0);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "twelve oh zero AM"
     );
 }
@@ -46,19 +40,34 @@ fn test_exact_24h_format() {
 
     let time = create_time_info(15, 47);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, true, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .use_24h(true)
+        ),
         "fifteen forty-seven"
     );
 
     let time = create_time_info(9, 5);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, true, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .use_24h(true)
+        ),
         "nine oh five"
     );
 
     let time = create_time_info(0, 0);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, true, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .use_24h(true)
+        ),
         "zero oh zero"
     );
 }
@@ -70,28 +79,28 @@ fn test_fuzzy_special_times() {
     // On the hour
     let time = create_time_info(3, 0);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
         "three o'clock"
     );
 
     // Quarter past
     let time = create_time_info(9, 15);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
         "quarter past nine AM"
     );
 
     // Half past
     let time = create_time_info(14, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
         "half past two PM"
     );
 
     // Quarter to
     let time = create_time_info(15, 45);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
         "quarter to four PM"
     );
 }
@@ -102,14 +111,24 @@ fn test_fuzzy_24h_format() {
 
     let time = create_time_info(15, 15);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, true, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Fuzzy)
+                .use_24h(true)
+        ),
         "quarter past fifteen"
     );
 
     let time = create_time_info(23, 45);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, true, false),
-        "quarter to zero"
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Fuzzy)
+                .use_24h(true)
+        ),
+        "quarter to midnight"
     );
 }
 
@@ -119,25 +138,37 @@ fn test_very_fuzzy() {
 
     let time = create_time_info(3, 5);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::VeryFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy)
+        ),
         "three o'clock"
     );
 
     let time = create_time_info(3, 20);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::VeryFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy)
+        ),
         "about quarter past three"
     );
 
     let time = create_time_info(3, 35);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::VeryFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy)
+        ),
         "about half past three"
     );
 
     let time = create_time_info(3, 50);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::VeryFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy)
+        ),
         "about quarter to four"
     );
 }
@@ -149,34 +180,49 @@ fn test_max_fuzzy() {
     // Morning
     let time = create_time_info(9, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "morning"
     );
 
     // Afternoon
     let time = create_time_info(14, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "afternoon"
     );
 
     // Evening
     let time = create_time_info(19, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "evening"
     );
 
     // Night
     let time = create_time_info(23, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "night"
     );
 
     let time = create_time_info(2, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "night"
     );
 }
@@ -186,7 +232,7 @@ fn test_edge_case_midnight() {
     let translator = EnglishTranslator;
     let time = create_time_info(0, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "twelve thirty AM"
     );
 }
@@ -196,7 +242,7 @@ fn test_edge_case_noon() {
     let translator = EnglishTranslator;
     let time = create_time_info(12, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "twelve thirty PM"
     );
 }
@@ -209,21 +255,36 @@ fn test_include_units_exact() {
 
     let time = create_time_info(3, 15);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .include_units(true)
+        ),
         "three hours fifteen minutes AM"
     );
 
     // Test singular
     let time = create_time_info(1, 1);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .include_units(true)
+        ),
         "one hour oh one minute AM"
     );
 
     // Test with 12
     let time = create_time_info(12, 0);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .include_units(true)
+        ),
         "twelve hours oh zero minutes PM"
     );
 }
@@ -235,14 +296,24 @@ fn test_include_units_fuzzy() {
     // Quarter past
     let time = create_time_info(3, 15);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Fuzzy)
+                .include_units(true)
+        ),
         "quarter past three hours AM"
     );
 
     // Half past
     let time = create_time_info(3, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Fuzzy)
+                .include_units(true)
+        ),
         "half past three hours AM"
     );
 }
@@ -253,13 +324,23 @@ fn test_include_units_very_fuzzy() {
 
     let time = create_time_info(3, 5);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::VeryFuzzy, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::VeryFuzzy)
+                .include_units(true)
+        ),
         "three hours o'clock"
     );
 
     let time = create_time_info(3, 20);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::VeryFuzzy, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::VeryFuzzy)
+                .include_units(true)
+        ),
         "about quarter past three hours"
     );
 }
@@ -270,14 +351,26 @@ fn test_include_units_24h_format() {
 
     let time = create_time_info(15, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, true, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .use_24h(true)
+                .include_units(true)
+        ),
         "fifteen hours thirty minutes"
     );
 
     // Test singular
     let time = create_time_info(1, 1);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, true, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .use_24h(true)
+                .include_units(true)
+        ),
         "one hour oh one minute"
     );
 }
@@ -289,7 +382,445 @@ fn test_max_fuzzy_ignores_units() {
     // Max fuzzy should ignore include_units flag
     let time = create_time_info(9, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, true)
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::MaxFuzzy)
+                .include_units(true)
+        )
+    );
+}
+
+#[test]
+fn test_past_and_future_tense() {
+    let translator = EnglishTranslator;
+    let past = TranslateOptions::new()
+        .level(FuzzinessLevel::VeryFuzzy)
+        .tense(Tense::Past);
+    let future = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .tense(Tense::Future);
+
+    let time = create_time_info(15, 20);
+    assert_eq!(
+        translator.translate_with(&time, &past),
+        "it was about quarter past three"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &future),
+        "it will be about twenty past three PM"
+    );
+
+    // Max-fuzzy period names are not affected
+    let time = create_time_info(9, 0);
+    assert_eq!(
+        translator.translate_with(&time, &past.level(FuzzinessLevel::MaxFuzzy)),
+        "morning"
+    );
+}
+
+#[test]
+fn test_rounded() {
+    let translator = EnglishTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Rounded { step_minutes: 5 });
+
+    let cases = [
+        (15, 8, "ten past three PM"),
+        (15, 14, "quarter past three PM"),
+        (15, 33, "twenty-five to four PM"),
+        (15, 58, "four o'clock"),
+        (11, 58, "noon"),
+    ];
+    for (hour, minute, expected) in cases {
+        let time = create_time_info(hour, minute);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+
+    let time = create_time_info(15, 37);
+    assert_eq!(
+        translator.translate_with(
+            &time,
+            &options.level(FuzzinessLevel::Rounded { step_minutes: 15 })
+        ),
+        "half past three PM"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.include_units(true).use_24h(true)),
+        "twenty-five minutes to sixteen hours"
+    );
+}
+
+#[test]
+fn test_floor_rounding() {
+    let translator = EnglishTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .rounding(RoundingMode::Floor);
+
+    let cases = [
+        (15, 0, "three o'clock"),
+        (15, 14, "about three o'clock"),
+        (15, 15, "quarter past three PM"),
+        (15, 29, "about quarter past three PM"),
+        (15, 52, "about quarter to four PM"),
+    ];
+    for (hour, minute, expected) in cases {
+        let time = create_time_info(hour, minute);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+
+    let time = create_time_info(15, 59);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::VeryFuzzy)),
+        "about half past three"
+    );
+    assert_eq!(
+        translator.translate_with(
+            &time,
+            &options.level(FuzzinessLevel::Rounded { step_minutes: 5 })
+        ),
+        "five to four PM"
+    );
+
+    // Very fuzzy is qualified the same way, without a meridiem
+    let very_fuzzy = options.level(FuzzinessLevel::VeryFuzzy);
+    let cases = [
+        (15, 8, "about three o'clock"),
+        (15, 30, "half past three"),
+        (23, 59, "about half past eleven"),
+    ];
+    for (hour, minute, expected) in cases {
+        let time = create_time_info(hour, minute);
+        assert_eq!(translator.translate_with(&time, &very_fuzzy), expected);
+    }
+}
+
+#[test]
+fn test_ceiling_rounding() {
+    let translator = EnglishTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .rounding(RoundingMode::Ceiling);
+
+    // "Almost" within half a step of the rounded time, "about" further off
+    let cases = [
+        (15, 1, "about quarter past three PM"),
+        (15, 10, "almost quarter past three PM"),
+        (15, 30, "half past three PM"),
+        (15, 31, "about quarter to four PM"),
+        (15, 40, "almost quarter to four PM"),
+        (15, 46, "about four o'clock"),
+        (23, 55, "almost midnight"),
+    ];
+    for (hour, minute, expected) in cases {
+        let time = create_time_info(hour, minute);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+
+    let time = create_time_info(15, 1);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::VeryFuzzy)),
+        "about half past three"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Past)),
+        "it was about quarter past three PM"
+    );
+    assert_eq!(
+        translator.translate_with(
+            &time,
+            &options.level(FuzzinessLevel::Rounded { step_minutes: 5 })
+        ),
+        "five past three PM"
+    );
+
+    let very_fuzzy = options.level(FuzzinessLevel::VeryFuzzy);
+    let cases = [
+        (15, 20, "almost half past three"),
+        (15, 40, "about four o'clock"),
+        (15, 50, "almost four o'clock"),
+    ];
+    for (hour, minute, expected) in cases {
+        let time = create_time_info(hour, minute);
+        assert_eq!(translator.translate_with(&time, &very_fuzzy), expected);
+    }
+}
+
+#[test]
+fn test_noon_and_midnight() {
+    let translator = EnglishTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let cases = [
+        (12, 0, "noon"),
+        (12, 15, "quarter past noon"),
+        (11, 55, "almost noon"),
+        (23, 45, "quarter to midnight"),
+        (0, 0, "midnight"),
+    ];
+    for (hour, minute, expected) in cases {
+        let time = create_time_info(hour, minute);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+
+    let time = create_time_info(0, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options.use_24h(true)),
+        "midnight"
+    );
+
+    let time = create_time_info(12, 5);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::MaxFuzzy)),
+        "noon"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::VeryFuzzy)),
+        "noon"
+    );
+}
+
+#[test]
+fn test_hour_names_off() {
+    let translator = EnglishTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .hour_names(false);
+
+    let time = create_time_info(12, 0);
+    assert_eq!(translator.translate_with(&time, &options), "twelve o'clock");
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::MaxFuzzy)),
+        "afternoon"
+    );
+
+    let time = create_time_info(0, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options.use_24h(true)),
+        "zero o'clock"
+    );
+
+    let time = create_time_info(12, 15);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "quarter past twelve PM"
+    );
+}
+
+#[test]
+fn test_custom_day_periods() {
+    let translator = EnglishTranslator;
+    // A night shift: the day starts in the evening
+    let periods = DayPeriods::parse("morning=18,afternoon=0,evening=6,night=10");
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::MaxFuzzy)
+        .day_periods(periods);
+
+    let time = create_time_info(19, 30);
+    assert_eq!(translator.translate_with(&time, &options), "morning");
+    assert_eq!(translator.day_period(&time, &options), DayPeriod::Morning);
+
+    let time = create_time_info(14, 30);
+    assert_eq!(translator.translate_with(&time, &options), "night");
+
+    let periods = DayPeriods::parse("early-morning=1,morning=7,late-morning=10,evening=18");
+    let time = create_time_info(10, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options.day_periods(periods)),
+        "late morning"
+    );
+}
+
+#[test]
+fn test_sun_periods() {
+    let translator = EnglishTranslator;
+    let london = Coordinates::new(51.5074, -0.1278).unwrap();
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::MaxFuzzy)
+        .location(Some(london));
+
+    // Midsummer: civil dawn 03:56, sunrise 04:43, solar noon 13:02,
+    // sunset 21:21, civil dusk 22:05 and solar midnight 01:02 (BST)
+    let cases = [
+        ("02:00", "night"),
+        ("04:20", "dawn"),
+        ("09:00", "morning"),
+        ("15:00", "afternoon"),
+        ("21:40", "dusk"),
+        ("23:30", "evening"),
+    ];
+    for (at, expected) in cases {
+        let time = parse_time(&format!("2024-06-21T{}:00+01:00", at)).unwrap();
+        assert_eq!(
+            translator.translate_with(&time, &options),
+            expected,
+            "{}",
+            at
+        );
+    }
+
+    // Without a date the hours are used
+    let time = create_time_info(21, 40);
+    assert_eq!(translator.translate_with(&time, &options), "evening");
+
+    // Reykjavik's midsummer night is a long twilight
+    let reykjavik = Coordinates::new(64.15, -21.94).unwrap();
+    let time = parse_time("2024-06-21T01:30:00+00:00").unwrap();
+    assert_eq!(
+        translator.translate_with(&time, &options.location(Some(reykjavik))),
+        "twilight"
+    );
+}
+
+#[test]
+fn test_day_level() {
+    let translator = EnglishTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Day);
+
+    // 2024-06-09 is a Sunday
+    let cases = [
+        ("2024-06-09T23:30:00", "Sunday night"),
+        ("2024-06-10T02:00:00", "late Sunday night"),
+        ("2024-06-10T09:40:00", "Monday morning"),
+        ("2024-06-10T15:00:00", "Monday afternoon"),
+        ("2024-06-10T18:00:00", "Monday evening"),
+    ];
+    for (at, expected) in cases {
+        let time = parse_time(&format!("{}+02:00", at)).unwrap();
+        assert_eq!(
+            translator.translate_with(&time, &options),
+            expected,
+            "{}",
+            at
+        );
+    }
+
+    // A period starting at midnight belongs to the new day
+    let options = options.day_periods(DayPeriods::parse("early-morning=0,late-morning=10"));
+    let time = parse_time("2024-06-10T02:00:00+02:00").unwrap();
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "early Monday morning"
+    );
+
+    // Without a date there is only the period
+    let time = create_time_info(9, 40);
+    assert_eq!(translator.translate_with(&time, &options), "early morning");
+}
+
+#[test]
+fn test_week_level() {
+    let translator = EnglishTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Week);
+
+    let cases = [
+        ("2024-06-10T09:00:00", "early in the week"),
+        ("2024-06-13T09:00:00", "midweek"),
+        ("2024-06-14T20:00:00", "end of the week"),
+        ("2024-06-15T12:00:00", "the weekend"),
+        // Still Sunday night
+        ("2024-06-17T01:00:00", "the weekend"),
+    ];
+    for (at, expected) in cases {
+        let time = parse_time(&format!("{}+02:00", at)).unwrap();
+        assert_eq!(
+            translator.translate_with(&time, &options),
+            expected,
+            "{}",
+            at
+        );
+    }
+}
+
+#[test]
+fn test_show_day() {
+    let translator = EnglishTranslator;
+    let time = parse_time("2024-06-10T15:15:00+02:00").unwrap();
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "quarter past three PM"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.show_day(ShowDay::Weekday)),
+        "quarter past three PM, Monday"
+    );
+
+    let options = options.show_day(ShowDay::Relative);
+    let day = |d| NaiveDate::from_ymd_opt(2024, 6, d);
+    assert_eq!(
+        translator.translate_with(&time, &options.today(day(10))),
+        "quarter past three PM, today"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.today(day(11))),
+        "quarter past three PM, yesterday"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.today(day(9))),
+        "quarter past three PM, tomorrow"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.today(day(20))),
+        "quarter past three PM, Monday"
+    );
+
+    // Tense wraps the whole phrase, day included
+    assert_eq!(
+        translator.translate_with(&time, &options.today(day(11)).tense(Tense::Past)),
+        "it was quarter past three PM, yesterday"
+    );
+
+    // Times without a date have nothing to show
+    let time = create_time_info(15, 15);
+    assert_eq!(
+        translator.translate_with(&time, &options.today(day(10))),
+        "quarter past three PM"
+    );
+}
+
+#[test]
+fn test_month_and_season_levels() {
+    let translator = EnglishTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Month);
+
+    let cases = [
+        ("2024-03-02", "early March"),
+        ("2024-06-15", "mid-June"),
+        ("2024-06-30", "late June"),
+    ];
+    for (at, expected) in cases {
+        let time = parse_time(&format!("{}T12:00:00+00:00", at)).unwrap();
+        assert_eq!(
+            translator.translate_with(&time, &options),
+            expected,
+            "{}",
+            at
+        );
+    }
+
+    let time = parse_time("2024-10-25T12:00:00+00:00").unwrap();
+    let options = options.level(FuzzinessLevel::Season);
+    assert_eq!(translator.translate_with(&time, &options), "mid-autumn");
+
+    // The hemisphere follows the location unless it is given
+    let sydney = Coordinates::new(-33.87, 151.21);
+    assert_eq!(
+        translator.translate_with(&time, &options.location(sydney)),
+        "mid-spring"
+    );
+    assert_eq!(
+        translator.translate_with(
+            &time,
+            &options
+                .location(sydney)
+                .hemisphere(Some(Hemisphere::Northern))
+        ),
+        "mid-autumn"
     );
 }
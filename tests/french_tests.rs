@@ -0,0 +1,180 @@
+use rust_fuzzy_clock::time::TimeInfo;
+use rust_fuzzy_clock::translator::{
+    FuzzinessLevel, Tense, TimeTranslator, TranslateOptions, french::FrenchTranslator,
+};
+
+fn create_time_info(hour24: u32, minute: u32) -> TimeInfo {
+    TimeInfo::new(hour24, minute).unwrap()
+}
+
+#[test]
+fn test_exact_12h_format() {
+    let translator = FrenchTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Exact);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "trois heures quarante-sept de l'après-midi"
+    );
+
+    let time = create_time_info(8, 5);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "huit heures cinq du matin"
+    );
+
+    let time = create_time_info(21, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "neuf heures du soir"
+    );
+}
+
+#[test]
+fn test_exact_24h_format() {
+    let translator = FrenchTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .use_24h(true);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "quinze heures quarante-sept"
+    );
+
+    let time = create_time_info(21, 31);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "vingt et une heures trente et une"
+    );
+}
+
+#[test]
+fn test_feminine_one() {
+    let translator = FrenchTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Exact);
+
+    let time = create_time_info(1, 1);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "une heure une du matin"
+    );
+}
+
+#[test]
+fn test_fuzzy_quarters() {
+    let translator = FrenchTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(3, 15);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "trois heures et quart du matin"
+    );
+
+    let time = create_time_info(15, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "trois heures et demie de l'après-midi"
+    );
+
+    let time = create_time_info(15, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "quatre heures moins le quart de l'après-midi"
+    );
+}
+
+#[test]
+fn test_noon_and_midnight() {
+    let translator = FrenchTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(12, 0);
+    assert_eq!(translator.translate_with(&time, &options), "midi pile");
+
+    // No "du matin" for midi, even though it is still morning
+    let time = create_time_info(11, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "midi moins le quart"
+    );
+
+    let time = create_time_info(0, 15);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "minuit et quart"
+    );
+
+    let time = create_time_info(0, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::Exact)),
+        "minuit"
+    );
+}
+
+#[test]
+fn test_very_fuzzy() {
+    let translator = FrenchTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy);
+
+    let time = create_time_info(15, 20);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "environ trois heures et quart"
+    );
+
+    let time = create_time_info(23, 55);
+    assert_eq!(translator.translate_with(&time, &options), "bientôt minuit");
+}
+
+#[test]
+fn test_max_fuzzy() {
+    let translator = FrenchTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy);
+
+    let cases = [(8, "matin"), (14, "après-midi"), (19, "soir"), (23, "nuit")];
+    for (hour, expected) in cases {
+        let time = create_time_info(hour, 0);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+}
+
+#[test]
+fn test_include_units_only_adds_minutes() {
+    let translator = FrenchTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Exact);
+
+    // "heures" is always there; units add "minutes"
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options.include_units(true)),
+        "trois heures quarante-sept minutes de l'après-midi"
+    );
+
+    let time = create_time_info(15, 1);
+    assert_eq!(
+        translator.translate_with(&time, &options.include_units(true).use_24h(true)),
+        "quinze heures une minute"
+    );
+}
+
+#[test]
+fn test_past_and_future_tense() {
+    let translator = FrenchTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(3, 15);
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Past)),
+        "il était trois heures et quart du matin"
+    );
+
+    let time = create_time_info(11, 55);
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Future)),
+        "il sera bientôt midi"
+    );
+}
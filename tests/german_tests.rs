@@ -0,0 +1,278 @@
+use rust_fuzzy_clock::time::{TimeInfo, parse_time};
+use rust_fuzzy_clock::translator::{
+    FuzzinessLevel, Style, Tense, TimeTranslator, TranslateOptions, german::GermanTranslator,
+};
+
+fn create_time_info(hour24: u32, minute: u32) -> TimeInfo {
+    TimeInfo::new(hour24, minute).unwrap()
+}
+
+#[test]
+fn test_exact_12h_format() {
+    let translator = GermanTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Exact);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "drei Uhr siebenundvierzig nachmittags"
+    );
+
+    let time = create_time_info(9, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "neun Uhr morgens"
+    );
+}
+
+#[test]
+fn test_exact_24h_format() {
+    let translator = GermanTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .use_24h(true);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "fünfzehn Uhr siebenundvierzig"
+    );
+
+    let time = create_time_info(21, 1);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "einundzwanzig Uhr eins"
+    );
+}
+
+#[test]
+fn test_ein_uhr() {
+    let translator = GermanTranslator;
+
+    // "ein" before "Uhr", "eins" on its own
+    let time = create_time_info(1, 0);
+    assert_eq!(
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
+        "ein Uhr"
+    );
+
+    let time = create_time_info(1, 5);
+    assert_eq!(
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
+        "fünf nach eins nachts"
+    );
+}
+
+#[test]
+fn test_eine_minute() {
+    let translator = GermanTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .include_units(true);
+
+    // "eine Minute", but "ein Uhr eins" without the label
+    let time = create_time_info(1, 1);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "ein Uhr eine Minute nachts"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.include_units(false)),
+        "ein Uhr eins nachts"
+    );
+
+    let time = create_time_info(1, 21);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "ein Uhr einundzwanzig Minuten nachts"
+    );
+}
+
+#[test]
+fn test_halb_looks_ahead() {
+    let translator = GermanTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(15, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "halb vier nachmittags"
+    );
+
+    let time = create_time_info(12, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options.use_24h(true)),
+        "halb dreizehn"
+    );
+}
+
+#[test]
+fn test_northern_quarters() {
+    let translator = GermanTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .style(Some(Style::Northern));
+
+    let time = create_time_info(15, 15);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "Viertel nach drei nachmittags"
+    );
+
+    let time = create_time_info(15, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "Viertel vor vier nachmittags"
+    );
+
+    // The northern style is also the default
+    assert_eq!(
+        translator.translate_with(&time, &options.style(None)),
+        "Viertel vor vier nachmittags"
+    );
+}
+
+#[test]
+fn test_southern_quarters() {
+    let translator = GermanTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .style(Some(Style::Southern));
+
+    let time = create_time_info(15, 15);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "viertel vier nachmittags"
+    );
+
+    let time = create_time_info(15, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "halb vier nachmittags"
+    );
+
+    let time = create_time_info(15, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "dreiviertel vier nachmittags"
+    );
+
+    let time = create_time_info(15, 20);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::VeryFuzzy)),
+        "etwa viertel vier"
+    );
+}
+
+#[test]
+fn test_supported_styles() {
+    let translator = GermanTranslator;
+    assert!(translator.supports_style(Style::Northern));
+    assert!(translator.supports_style(Style::Southern));
+}
+
+#[test]
+fn test_max_fuzzy() {
+    let translator = GermanTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy);
+
+    let cases = [
+        (8, "Morgen"),
+        (14, "Nachmittag"),
+        (19, "Abend"),
+        (23, "Nacht"),
+    ];
+    for (hour, expected) in cases {
+        let time = create_time_info(hour, 0);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+}
+
+#[test]
+fn test_past_and_future_tense() {
+    let translator = GermanTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy);
+
+    let time = create_time_info(15, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Past)),
+        "es war etwa halb vier"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Future)),
+        "es wird etwa halb vier sein"
+    );
+}
+
+#[test]
+fn test_rounded_counts_from_halb() {
+    let translator = GermanTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Rounded { step_minutes: 5 })
+        .use_24h(true);
+
+    let cases = [
+        (15, 9, "zehn nach fünfzehn"),
+        (15, 24, "fünf vor halb sechzehn"),
+        (15, 36, "fünf nach halb sechzehn"),
+        (15, 41, "zwanzig vor sechzehn"),
+        (15, 58, "sechzehn Uhr"),
+    ];
+    for (hour, minute, expected) in cases {
+        let time = create_time_info(hour, minute);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+
+    let time = create_time_info(15, 14);
+    assert_eq!(
+        translator.translate_with(&time, &options.style(Some(Style::Southern))),
+        "viertel sechzehn"
+    );
+}
+
+#[test]
+fn test_noon_and_midnight() {
+    let translator = GermanTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(12, 0);
+    assert_eq!(translator.translate_with(&time, &options), "Mittag");
+    assert_eq!(
+        translator.translate_with(&time, &options.hour_names(false)),
+        "zwölf Uhr"
+    );
+
+    let time = create_time_info(0, 5);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::VeryFuzzy)),
+        "etwa Mitternacht"
+    );
+}
+
+#[test]
+fn test_day_and_week_levels() {
+    let translator = GermanTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Day);
+
+    let time = parse_time("2024-06-09T20:00:00+02:00").unwrap();
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "am Sonntagabend"
+    );
+
+    let time = parse_time("2024-06-10T02:00:00+02:00").unwrap();
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "spät in der Sonntagnacht"
+    );
+
+    let time = parse_time("2024-06-12T10:00:00+02:00").unwrap();
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "am Mittwochmorgen"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::Week)),
+        "Mitte der Woche"
+    );
+}
@@ -35,9 +35,29 @@ fn get_bin_path() -> PathBuf {
     panic!("Could not find {} binary in {:?}", binary_name, target_dir);
 }
 
+/// The binary, shielded from the developer's own config file and
+/// `FUZZY_CLOCK_*` variables.
+fn fuzzy_clock() -> Command {
+    let empty_config_home = std::env::temp_dir().join(format!(
+        "rust-fuzzy-clock-test-config-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&empty_config_home).expect("Failed to create config dir");
+
+    let mut command = Command::new(get_bin_path());
+    command.env("XDG_CONFIG_HOME", empty_config_home);
+    for (name, _) in std::env::vars_os() {
+        if name.to_string_lossy().starts_with("FUZZY_CLOCK_") {
+            command.env_remove(name);
+        }
+    }
+    command
+}
+
 #[test]
 fn test_cli_default_options() {
-    let output = Command::new(get_bin_path())
+    let output = fuzzy_clock()
+        .env("LC_ALL", "en_US.UTF-8")
         .output()
         .expect("Failed to execute command");
 
@@ -50,7 +70,7 @@ fn test_cli_default_options() {
 
 #[test]
 fn test_cli_english_exact() {
-    let output = Command::new(get_bin_path())
+    let output = fuzzy_clock()
         .args(["-l", "english", "-f", "exact"])
         .output()
         .expect("Failed to execute command");
@@ -62,7 +82,7 @@ fn test_cli_english_exact() {
 
 #[test]
 fn test_cli_24_hour_format() {
-    let output = Command::new(get_bin_path())
+    let output = fuzzy_clock()
         .args(["-l", "english", "-f", "exact", "--24-hour"])
         .output()
         .expect("Failed to execute command");
@@ -77,7 +97,7 @@ fn test_cli_24_hour_format() {
 
 #[test]
 fn test_cli_spanish() {
-    let output = Command::new(get_bin_path())
+    let output = fuzzy_clock()
         .args(["-l", "spanish", "-f", "exact"])
         .output()
         .expect("Failed to execute command");
@@ -91,7 +111,7 @@ fn test_cli_spanish() {
 
 #[test]
 fn test_cli_portuguese() {
-    let output = Command::new(get_bin_path())
+    let output = fuzzy_clock()
         .args(["-l", "portuguese", "-f", "exact"])
         .output()
         .expect("Failed to execute command");
@@ -105,7 +125,7 @@ fn test_cli_portuguese() {
 
 #[test]
 fn test_cli_max_fuzzy() {
-    let output = Command::new(get_bin_path())
+    let output = fuzzy_clock()
         .args(["-l", "english", "-f", "max-fuzzy"])
         .output()
         .expect("Failed to execute command");
@@ -122,8 +142,8 @@ fn test_cli_max_fuzzy() {
 
 #[test]
 fn test_cli_invalid_language() {
-    let output = Command::new(get_bin_path())
-        .args(["-l", "french"])
+    let output = fuzzy_clock()
+        .args(["-l", "klingon"])
         .output()
         .expect("Failed to execute command");
 
@@ -134,7 +154,7 @@ fn test_cli_invalid_language() {
 
 #[test]
 fn test_cli_invalid_fuzzyness() {
-    let output = Command::new(get_bin_path())
+    let output = fuzzy_clock()
         .args(["-f", "super-fuzzy"])
         .output()
         .expect("Failed to execute command");
@@ -146,7 +166,7 @@ fn test_cli_invalid_fuzzyness() {
 
 #[test]
 fn test_cli_help() {
-    let output = Command::new(get_bin_path())
+    let output = fuzzy_clock()
         .args(["--help"])
         .output()
         .expect("Failed to execute command");
@@ -163,7 +183,7 @@ fn test_cli_help() {
 
 #[test]
 fn test_cli_short_flags() {
-    let output = Command::new(get_bin_path())
+    let output = fuzzy_clock()
         .args(["-l", "en", "-f", "fuzzy"])
         .output()
         .expect("Failed to execute command");
@@ -174,7 +194,7 @@ fn test_cli_short_flags() {
 #[test]
 fn test_cli_all_fuzziness_levels() {
     for level in &["exact", "fuzzy", "very-fuzzy", "max-fuzzy"] {
-        let output = Command::new(get_bin_path())
+        let output = fuzzy_clock()
             .args(["-f", level])
             .output()
             .expect("Failed to execute command");
@@ -190,7 +210,7 @@ fn test_cli_all_fuzziness_levels() {
 #[test]
 fn test_cli_all_languages() {
     for lang in &["english", "spanish", "portuguese"] {
-        let output = Command::new(get_bin_path())
+        let output = fuzzy_clock()
             .args(["-l", lang])
             .output()
             .expect("Failed to execute command");
@@ -203,7 +223,7 @@ fn test_cli_all_languages() {
 
 #[test]
 fn test_cli_include_units_flag() {
-    let output = Command::new(get_bin_path())
+    let output = fuzzy_clock()
         .args(["-l", "english", "-f", "exact", "--include-units"])
         .output()
         .expect("Failed to execute command");
@@ -217,7 +237,7 @@ fn test_cli_include_units_flag() {
 
 #[test]
 fn test_cli_include_units_with_24h() {
-    let output = Command::new(get_bin_path())
+    let output = fuzzy_clock()
         .args([
             "-l",
             "english",
@@ -241,7 +261,7 @@ fn test_cli_include_units_with_24h() {
 #[test]
 fn test_cli_include_units_all_languages() {
     for lang in &["english", "spanish", "portuguese"] {
-        let output = Command::new(get_bin_path())
+        let output = fuzzy_clock()
             .args(["-l", lang, "--include-units"])
             .output()
             .expect("Failed to execute command");
@@ -253,3 +273,632 @@ fn test_cli_include_units_all_languages() {
         );
     }
 }
+
+#[test]
+fn test_cli_at_time() {
+    let output = fuzzy_clock()
+        .args(["-l", "english", "-f", "fuzzy", "--at", "23:58"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "almost midnight");
+}
+
+#[test]
+fn test_cli_rounded() {
+    let output = fuzzy_clock()
+        .args(["-l", "english", "-f", "rounded", "--at", "15:33"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "twenty-five to four PM");
+
+    let output = fuzzy_clock()
+        .args(["-l", "english", "-f", "rounded-7", "--at", "15:33"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_cli_rounding() {
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "en",
+            "-f",
+            "fuzzy",
+            "--rounding",
+            "ceiling",
+            "--at",
+            "15:40",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "almost quarter to four PM");
+
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "en",
+            "-f",
+            "fuzzy",
+            "--rounding",
+            "up",
+            "--at",
+            "15:31",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown rounding mode"));
+}
+
+#[test]
+fn test_cli_no_hour_names() {
+    let output = fuzzy_clock()
+        .args(["-l", "en", "-f", "fuzzy", "--at", "12:15"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "quarter past noon");
+
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "en",
+            "-f",
+            "fuzzy",
+            "--no-hour-names",
+            "--at",
+            "12:15",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "quarter past twelve PM");
+}
+
+#[test]
+fn test_cli_day_periods() {
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "en",
+            "-f",
+            "max-fuzzy",
+            "--day-periods",
+            "morning=18,afternoon=0,evening=6,night=10",
+            "--at",
+            "19:30",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "morning");
+
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "en",
+            "-f",
+            "max-fuzzy",
+            "--day-periods",
+            "brunch=10",
+            "--at",
+            "19:30",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Invalid day periods 'brunch=10'"));
+}
+
+#[test]
+fn test_cli_sun_periods() {
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "en",
+            "-f",
+            "max-fuzzy",
+            "--latitude",
+            "-33.87",
+            "--longitude",
+            "151.21",
+            "--at",
+            "2024-12-21T20:30:00+11:00",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "dusk");
+
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "en",
+            "-f",
+            "max-fuzzy",
+            "--latitude",
+            "51.5",
+            "--at",
+            "20:30",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--latitude and --longitude must be given together"));
+}
+
+#[test]
+fn test_cli_day_levels() {
+    let output = fuzzy_clock()
+        .args(["-l", "en", "-f", "day", "--at", "2024-06-10T02:00:00+02:00"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "late Sunday night");
+
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "en",
+            "-f",
+            "fuzzy",
+            "--show-day",
+            "weekday",
+            "--at",
+            "2024-06-12T15:15:00+02:00",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "quarter past three PM, Wednesday");
+
+    let output = fuzzy_clock()
+        .args(["--show-day", "monthly", "--at", "15:15"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown show-day value"));
+}
+
+#[test]
+fn test_cli_month_and_season_levels() {
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "en",
+            "-f",
+            "month",
+            "--at",
+            "2024-03-02T12:00:00+00:00",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "early March");
+
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "pt",
+            "-f",
+            "season",
+            "--hemisphere",
+            "south",
+            "--at",
+            "2024-07-10T12:00:00-03:00",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "início do inverno");
+
+    let output = fuzzy_clock()
+        .args(["--hemisphere", "east", "--at", "12:00"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown hemisphere"));
+}
+
+#[test]
+fn test_cli_at_time_12h_input() {
+    let output = fuzzy_clock()
+        .args(["-l", "english", "--at", "3:47pm"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "three forty-seven PM");
+}
+
+#[test]
+fn test_cli_invalid_at_time() {
+    let output = fuzzy_clock()
+        .args(["--at", "25:61"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Invalid time"));
+}
+
+#[test]
+fn test_cli_timezone() {
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "english",
+            "--timezone",
+            "America/Chicago",
+            "--at",
+            "2024-01-15T12:00:00Z",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "six oh zero AM");
+}
+
+#[test]
+fn test_cli_invalid_timezone() {
+    let output = fuzzy_clock()
+        .args(["--timezone", "Mars/Olympus_Mons"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown timezone"));
+}
+
+#[test]
+fn test_cli_watch_prints_immediately() {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+
+    let mut child = fuzzy_clock()
+        .args(["-l", "english", "-f", "very-fuzzy", "--watch"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute command");
+
+    // The first line must arrive without waiting for the process to exit
+    let mut reader = BufReader::new(child.stdout.take().unwrap());
+    let mut line = String::new();
+    reader.read_line(&mut line).expect("Failed to read line");
+
+    child.kill().expect("Failed to stop watch mode");
+    let _ = child.wait();
+
+    assert!(!line.trim().is_empty());
+}
+
+#[test]
+fn test_cli_at_wins_over_watch() {
+    let output = fuzzy_clock()
+        .args(["-l", "en", "--watch", "--at", "12:00"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "twelve oh zero PM");
+
+    let output = fuzzy_clock()
+        .env("FUZZY_CLOCK_WATCH", "1")
+        .args(["-l", "en", "--at", "12:00"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "twelve oh zero PM");
+}
+
+#[test]
+fn test_cli_config_file_and_env_priority() {
+    let path = std::env::temp_dir().join(format!(
+        "rust-fuzzy-clock-cli-config-{}.toml",
+        std::process::id()
+    ));
+    std::fs::write(&path, "language = \"pt\"\nfuzziness = \"fuzzy\"\n").unwrap();
+
+    // Config file alone
+    let output = fuzzy_clock()
+        .args(["--config", path.to_str().unwrap(), "--at", "15:45"])
+        .output()
+        .expect("Failed to execute command");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "quinze para quatro da tarde");
+
+    // Environment variables win over the config file
+    let output = fuzzy_clock()
+        .args(["--config", path.to_str().unwrap(), "--at", "15:45"])
+        .env("FUZZY_CLOCK_LANGUAGE", "es")
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "cuarto para cuatro PM");
+
+    // Explicit flags win over both
+    let output = fuzzy_clock()
+        .args([
+            "--config",
+            path.to_str().unwrap(),
+            "--at",
+            "15:45",
+            "-l",
+            "en",
+        ])
+        .env("FUZZY_CLOCK_LANGUAGE", "es")
+        .output()
+        .expect("Failed to execute command");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "quarter to four PM");
+}
+
+#[test]
+fn test_cli_auto_language() {
+    let output = fuzzy_clock()
+        .args(["-f", "fuzzy", "--at", "15:45"])
+        .env("LC_ALL", "")
+        .env("LC_TIME", "pt_PT.UTF-8")
+        .env("LANG", "en_US.UTF-8")
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "um quarto para as quatro da tarde");
+}
+
+#[test]
+fn test_cli_auto_language_fallback() {
+    let output = fuzzy_clock()
+        .args(["-l", "auto", "-f", "fuzzy", "--at", "15:45"])
+        .env("LC_ALL", "C")
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "quarter to four PM");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Warning"));
+}
+
+#[test]
+fn test_cli_print_config() {
+    let output = fuzzy_clock()
+        .args(["--print-config", "-l", "es", "--24-hour"])
+        .env("XDG_CONFIG_HOME", "/nonexistent")
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("language = \"es\""));
+    assert!(stdout.contains("24-hour = true"));
+}
+
+#[test]
+fn test_cli_bad_config_key() {
+    let path = std::env::temp_dir().join(format!(
+        "rust-fuzzy-clock-bad-config-{}.toml",
+        std::process::id()
+    ));
+    std::fs::write(&path, "language = \"es\"\nlanguaje = \"pt\"\n").unwrap();
+
+    let output = fuzzy_clock()
+        .args(["--config", path.to_str().unwrap()])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("line 2"));
+    assert!(stderr.contains("languaje"));
+}
+
+#[test]
+fn test_cli_json_format() {
+    let output = fuzzy_clock()
+        .args([
+            "-l", "pt", "-f", "fuzzy", "--at", "14:30", "--format", "json",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["phrase"], "duas e meia da tarde");
+    assert_eq!(json["language"], "pt");
+    assert_eq!(json["hour24"], 14);
+    assert_eq!(json["period"], "afternoon");
+}
+
+#[test]
+fn test_cli_invalid_format() {
+    let output = fuzzy_clock()
+        .args(["--format", "xml"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown output format"));
+}
+
+#[test]
+fn test_cli_waybar_format() {
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "en",
+            "-f",
+            "very-fuzzy",
+            "--at",
+            "7:20",
+            "--format",
+            "waybar",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["text"], "about quarter past seven");
+    assert_eq!(json["tooltip"], "seven twenty AM");
+    assert_eq!(json["class"], "morning");
+}
+
+#[test]
+fn test_cli_polybar_keeps_running() {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+
+    let mut child = fuzzy_clock()
+        .args(["-f", "fuzzy", "--format", "polybar"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute command");
+
+    let mut reader = BufReader::new(child.stdout.take().unwrap());
+    let mut line = String::new();
+    reader.read_line(&mut line).expect("Failed to read line");
+
+    // Still running after the first line
+    assert!(child.try_wait().unwrap().is_none());
+    child.kill().expect("Failed to stop polybar mode");
+    let _ = child.wait();
+
+    assert!(!line.trim().is_empty());
+}
+
+#[test]
+fn test_cli_tense() {
+    let output = fuzzy_clock()
+        .args(["-l", "es", "-f", "fuzzy", "--at", "1:00", "--tense", "past"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "era la una en punto");
+
+    let output = fuzzy_clock()
+        .args(["-l", "pt", "-f", "fuzzy", "--at", "3:50"])
+        .env("FUZZY_CLOCK_TENSE", "future")
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "serão quase quatro");
+}
+
+#[test]
+fn test_cli_invalid_tense() {
+    let output = fuzzy_clock()
+        .args(["--tense", "pluperfect"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown tense"));
+}
+
+#[test]
+fn test_cli_style() {
+    let output = fuzzy_clock()
+        .args([
+            "-l", "de", "-f", "fuzzy", "--at", "15:45", "--style", "southern",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "dreiviertel vier nachmittags");
+
+    // A BCP 47 tag picks the regional style, unless one is given
+    let output = fuzzy_clock()
+        .args(["-l", "pt-PT", "-f", "fuzzy", "--at", "15:45"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "um quarto para as quatro da tarde");
+
+    let output = fuzzy_clock()
+        .args([
+            "-l",
+            "pt-PT",
+            "-f",
+            "fuzzy",
+            "--at",
+            "15:45",
+            "--style",
+            "brazilian",
+        ])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim(), "quinze para quatro da tarde");
+
+    let output = fuzzy_clock()
+        .args(["-l", "en", "--style", "southern"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not available"));
+}
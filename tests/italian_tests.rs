@@ -0,0 +1,224 @@
+use rust_fuzzy_clock::time::TimeInfo;
+use rust_fuzzy_clock::translator::{
+    FuzzinessLevel, Tense, TimeTranslator, TranslateOptions, italian::ItalianTranslator,
+};
+
+fn create_time_info(hour24: u32, minute: u32) -> TimeInfo {
+    TimeInfo::new(hour24, minute).unwrap()
+}
+
+#[test]
+fn test_exact_12h_format() {
+    let translator = ItalianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Exact);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "sono le tre e quarantasette del pomeriggio"
+    );
+
+    let time = create_time_info(9, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "sono le nove del mattino"
+    );
+}
+
+#[test]
+fn test_exact_24h_format() {
+    let translator = ItalianTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .use_24h(true);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "sono le quindici e quarantasette"
+    );
+
+    let time = create_time_info(13, 5);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "sono le tredici e cinque"
+    );
+}
+
+#[test]
+fn test_verb_agrees_with_hour() {
+    let translator = ItalianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(1, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "è l'una in punto"
+    );
+
+    let time = create_time_info(2, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "sono le due in punto"
+    );
+
+    // "Meno un quarto" is about the next hour
+    let time = create_time_info(12, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "è l'una meno un quarto del pomeriggio"
+    );
+}
+
+#[test]
+fn test_fuzzy_quarters() {
+    let translator = ItalianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(15, 15);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "sono le tre e un quarto del pomeriggio"
+    );
+
+    let time = create_time_info(15, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "sono le tre e mezza del pomeriggio"
+    );
+
+    let time = create_time_info(15, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "sono le quattro meno un quarto del pomeriggio"
+    );
+}
+
+#[test]
+fn test_noon_and_midnight() {
+    let translator = ItalianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(12, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "è mezzogiorno in punto"
+    );
+
+    let time = create_time_info(11, 55);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "è quasi mezzogiorno"
+    );
+
+    let time = create_time_info(0, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "è mezzanotte e mezza"
+    );
+}
+
+#[test]
+fn test_very_fuzzy() {
+    let translator = ItalianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy);
+
+    let time = create_time_info(15, 20);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "sono circa le tre e un quarto"
+    );
+
+    let time = create_time_info(1, 5);
+    assert_eq!(translator.translate_with(&time, &options), "è circa l'una");
+}
+
+#[test]
+fn test_max_fuzzy() {
+    let translator = ItalianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy);
+
+    let cases = [
+        (8, "mattina"),
+        (14, "pomeriggio"),
+        (19, "sera"),
+        (23, "notte"),
+    ];
+    for (hour, expected) in cases {
+        let time = create_time_info(hour, 0);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+}
+
+#[test]
+fn test_include_units_only_adds_minutes() {
+    let translator = ItalianTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .include_units(true);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "sono le tre e quarantasette minuti del pomeriggio"
+    );
+
+    // One minute is counted as "un minuto", but left alone as "e uno"
+    let time = create_time_info(1, 1);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "è l'una e un minuto di notte"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.include_units(false)),
+        "è l'una e uno di notte"
+    );
+}
+
+#[test]
+fn test_half_past_agrees_with_hour() {
+    let translator = ItalianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(1, 33);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "è l'una e mezza passata di notte"
+    );
+
+    let time = create_time_info(3, 33);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "sono le tre e mezza passate di notte"
+    );
+
+    let time = create_time_info(12, 33);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "è mezzogiorno e mezza passata"
+    );
+}
+
+#[test]
+fn test_past_and_future_tense() {
+    let translator = ItalianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(1, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Past)),
+        "era l'una in punto"
+    );
+
+    let time = create_time_info(15, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Future)),
+        "saranno le quattro meno un quarto del pomeriggio"
+    );
+
+    let time = create_time_info(23, 58);
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Past)),
+        "era quasi mezzanotte"
+    );
+}
@@ -0,0 +1,227 @@
+use rust_fuzzy_clock::time::{TimeInfo, parse_time};
+use rust_fuzzy_clock::translator::{
+    FuzzinessLevel, Style, Tense, TimeTranslator, TranslateOptions, japanese::JapaneseTranslator,
+};
+
+fn create_time_info(hour24: u32, minute: u32) -> TimeInfo {
+    TimeInfo::new(hour24, minute).unwrap()
+}
+
+#[test]
+fn test_exact_12h_format() {
+    let translator = JapaneseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Exact);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "午後三時四十七分"
+    );
+
+    let time = create_time_info(9, 0);
+    assert_eq!(translator.translate_with(&time, &options), "午前九時");
+}
+
+#[test]
+fn test_exact_24h_format() {
+    let translator = JapaneseTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .use_24h(true);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(translator.translate_with(&time, &options), "十五時四十七分");
+
+    let time = create_time_info(0, 5);
+    assert_eq!(translator.translate_with(&time, &options), "零時五分");
+}
+
+#[test]
+fn test_fuzzy() {
+    let translator = JapaneseTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .use_24h(true);
+
+    let time = create_time_info(3, 30);
+    assert_eq!(translator.translate_with(&time, &options), "三時半");
+
+    let time = create_time_info(3, 45);
+    assert_eq!(translator.translate_with(&time, &options), "四時十五分前");
+
+    let time = create_time_info(3, 0);
+    assert_eq!(translator.translate_with(&time, &options), "三時ちょうど");
+
+    let time = create_time_info(15, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options.use_24h(false)),
+        "午後三時半"
+    );
+}
+
+#[test]
+fn test_very_fuzzy() {
+    let translator = JapaneseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy);
+
+    let time = create_time_info(15, 20);
+    assert_eq!(translator.translate_with(&time, &options), "三時十五分ごろ");
+
+    let time = create_time_info(15, 55);
+    assert_eq!(translator.translate_with(&time, &options), "もうすぐ四時");
+}
+
+#[test]
+fn test_max_fuzzy() {
+    let translator = JapaneseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy);
+
+    let cases = [(8, "朝"), (13, "昼"), (17, "夕方"), (22, "夜")];
+    for (hour, expected) in cases {
+        let time = create_time_info(hour, 0);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+}
+
+#[test]
+fn test_digits_style() {
+    let translator = JapaneseTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .style(Some(Style::Digits));
+
+    let time = create_time_info(15, 47);
+    assert_eq!(translator.translate_with(&time, &options), "午後3時47分");
+
+    let time = create_time_info(15, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::Fuzzy)),
+        "午後4時15分前"
+    );
+}
+
+#[test]
+fn test_kana_style() {
+    let translator = JapaneseTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .style(Some(Style::Kana));
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "ごご さんじ よんじゅうななふん"
+    );
+
+    // Irregular readings of the counters
+    let time = create_time_info(4, 1);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "ごぜん よじ いっぷん"
+    );
+
+    let time = create_time_info(19, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::Fuzzy).use_24h(true)),
+        "じゅうくじ はん"
+    );
+
+    let time = create_time_info(22, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::MaxFuzzy)),
+        "よる"
+    );
+}
+
+#[test]
+fn test_supported_styles() {
+    let translator = JapaneseTranslator;
+    assert!(translator.supports_style(Style::Kanji));
+    assert!(translator.supports_style(Style::Digits));
+    assert!(translator.supports_style(Style::Kana));
+    assert!(!translator.supports_style(Style::Southern));
+}
+
+#[test]
+fn test_include_units_has_no_effect() {
+    let translator = JapaneseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Exact);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options.include_units(true)),
+        translator.translate_with(&time, &options)
+    );
+}
+
+#[test]
+fn test_past_and_future_tense() {
+    let translator = JapaneseTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .use_24h(true);
+
+    let time = create_time_info(3, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Past)),
+        "三時半でした"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Future)),
+        "三時半になります"
+    );
+}
+
+#[test]
+fn test_month_level_styles() {
+    let translator = JapaneseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Month);
+    let time = parse_time("2024-06-25T12:00:00+09:00").unwrap();
+
+    assert_eq!(translator.translate_with(&time, &options), "六月下旬");
+    assert_eq!(
+        translator.translate_with(&time, &options.style(Some(Style::Digits))),
+        "6月下旬"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.style(Some(Style::Kana))),
+        "ろくがつ げじゅん"
+    );
+}
+
+#[test]
+fn test_noon_and_midnight_by_name() {
+    let translator = JapaneseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(12, 0);
+    assert_eq!(translator.translate_with(&time, &options), "正午ちょうど");
+    assert_eq!(
+        translator.translate_with(&time, &options.hour_names(false)),
+        "十二時ちょうど"
+    );
+
+    let time = create_time_info(0, 3);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::VeryFuzzy)),
+        "午前零時ごろ"
+    );
+
+    // Named, so that the meridiem of the current hour does not mislead
+    let time = create_time_info(11, 45);
+    assert_eq!(translator.translate_with(&time, &options), "正午十五分前");
+
+    let time = create_time_info(23, 50);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "午前零時十分前ごろ"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.style(Some(Style::Digits))),
+        "午前0時10分前ごろ"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.style(Some(Style::Kana))),
+        "ごぜん れいじ じゅっぷん まえ ごろ"
+    );
+}
@@ -0,0 +1,607 @@
+use rust_fuzzy_clock::time::{TimeInfo, parse_time};
+use rust_fuzzy_clock::translator::pack::{LanguagePack, PackTranslator};
+use rust_fuzzy_clock::translator::{
+    DayPeriod, FuzzinessLevel, RoundingMode, Style, Tense, TimeTranslator, TranslateOptions,
+    chinese::ChineseTranslator, english::EnglishTranslator, french::FrenchTranslator,
+    german::GermanTranslator, italian::ItalianTranslator, japanese::JapaneseTranslator,
+    periods::DayPeriods, portuguese::PortugueseTranslator, russian::RussianTranslator,
+    spanish::SpanishTranslator,
+};
+
+// A deliberately small pack: Esperanto-like words, two fuzzy buckets
+const TOY_PACK: &str = r#"
+[language]
+code = "eo"
+name = "Esperanto"
+
+[numbers]
+words = ["nul", "unu", "du", "tri", "kvar", "kvin", "ses", "sep", "ok", "naŭ", "dek"]
+tens = ["", "dek", "dudek", "tridek", "kvardek", "kvindek"]
+tens-separator = " "
+minute-zero = "nul"
+
+[units]
+hour = { one = "horo", other = "horoj" }
+minute = { one = "minuto", other = "minutoj" }
+
+[exact]
+text = "{hour} {hour_unit} {padded_minute} {minute_unit} {meridiem}"
+
+[[meridiem]]
+from = 0
+text = "atm"
+
+[[meridiem]]
+from = 12
+text = "ptm"
+
+[[fuzzy]]
+minutes = [0, 29]
+text = "post {hour}"
+
+[[fuzzy]]
+minutes = [30, 59]
+text = "antaŭ {next_hour} {next_hour_unit}"
+
+[[very-fuzzy]]
+minutes = [0, 59]
+text = "ĉirkaŭ {hour}"
+
+[day-periods.names]
+early-morning = "frumateno"
+dawn = "tagiĝo"
+morning = "mateno"
+late-morning = "antaŭtagmezo"
+noon = "tagmezo"
+afternoon = "posttagmezo"
+dusk = "krepusko"
+evening = "vespero"
+night = "nokto"
+twilight = "duonlumo"
+
+[day-periods.starts]
+morning = 6
+night = 18
+"#;
+
+const TOY_CALENDAR: &str = r#"
+[weekdays]
+names = ["lundo", "mardo", "merkredo", "ĵaŭdo", "vendredo", "sabato", "dimanĉo"]
+
+[day]
+text = "{weekday}a {period}"
+late = "malfrua {phrase}"
+attach = "{phrase}, {day}"
+relative = { yesterday = "hieraŭ", today = "hodiaŭ", tomorrow = "morgaŭ" }
+
+[week]
+start = "komence de la semajno"
+middle = "meze de la semajno"
+end = "fine de la semajno"
+weekend = "semajnfine"
+
+[months]
+names = ["januaro", "februaro", "marto", "aprilo", "majo", "junio", "julio", "aŭgusto", "septembro", "oktobro", "novembro", "decembro"]
+
+[month]
+early = "komence de {month}"
+middle = "meze de {month}"
+late = "fine de {month}"
+"#;
+
+fn create_time_info(hour24: u32, minute: u32) -> TimeInfo {
+    TimeInfo::new(hour24, minute).unwrap()
+}
+
+fn toy_translator() -> PackTranslator {
+    PackTranslator::new(LanguagePack::from_toml_str(TOY_PACK).unwrap())
+}
+
+#[test]
+fn test_custom_pack_exact() {
+    let translator = toy_translator();
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &TranslateOptions::new()),
+        "tri kvardek sep ptm"
+    );
+
+    let time = create_time_info(1, 5);
+    assert_eq!(
+        translator.translate_with(&time, &TranslateOptions::new().include_units(true)),
+        "unu horo nul kvin minutoj atm"
+    );
+}
+
+#[test]
+fn test_custom_pack_buckets() {
+    let translator = toy_translator();
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(11, 40);
+    assert_eq!(translator.translate_with(&time, &options), "antaŭ dek du");
+
+    let options = options.use_24h(true).include_units(true);
+    let time = create_time_info(23, 40);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "antaŭ nul horoj"
+    );
+}
+
+#[test]
+fn test_custom_pack_day_periods_wrap() {
+    let translator = toy_translator();
+    let options = TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy);
+
+    let time = create_time_info(3, 0);
+    assert_eq!(translator.translate_with(&time, &options), "nokto");
+    assert_eq!(
+        translator.day_period(&time, &TranslateOptions::new()),
+        DayPeriod::Night
+    );
+
+    let time = create_time_info(6, 0);
+    assert_eq!(translator.translate_with(&time, &options), "mateno");
+}
+
+#[test]
+fn test_options_day_periods_override_pack_starts() {
+    let translator = toy_translator();
+    let periods = DayPeriods::parse("noon=11,evening=16");
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::MaxFuzzy)
+        .day_periods(periods);
+
+    let time = create_time_info(3, 0);
+    assert_eq!(translator.translate_with(&time, &options), "vespero");
+    assert_eq!(translator.day_period(&time, &options), DayPeriod::Evening);
+
+    let time = create_time_info(12, 0);
+    assert_eq!(translator.translate_with(&time, &options), "tagmezo");
+}
+
+#[test]
+fn test_day_period_without_name_is_rejected() {
+    let broken = TOY_PACK.replace("noon = \"tagmezo\"\n", "");
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("'noon'"), "{}", err);
+
+    let broken = TOY_PACK.replace("night = 18", "night = 6");
+    assert!(LanguagePack::from_toml_str(&broken).is_err());
+}
+
+#[test]
+fn test_bucket_gap_is_rejected() {
+    let broken = TOY_PACK.replace("minutes = [30, 59]", "minutes = [31, 59]");
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("fuzzy"), "{}", err);
+}
+
+#[test]
+fn test_unknown_placeholder_is_rejected() {
+    let broken = TOY_PACK.replace("post {hour}", "post {hora}");
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("{hora}"), "{}", err);
+}
+
+#[test]
+fn test_unknown_form_is_rejected() {
+    let broken = TOY_PACK.replace(
+        "minute-zero = \"nul\"",
+        "minute-zero = \"nul\"\nhour-form = \"feminine\"",
+    );
+    assert!(LanguagePack::from_toml_str(&broken).is_err());
+
+    let broken = TOY_PACK.replace(
+        "minute = { one",
+        "minute-form = \"feminine\"\nminute = { one",
+    );
+    assert!(LanguagePack::from_toml_str(&broken).is_err());
+}
+
+#[test]
+fn test_unknown_key_is_rejected() {
+    let broken = TOY_PACK.replace("[units]", "colour = \"red\"\n\n[units]");
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("colour"), "{}", err);
+}
+
+#[test]
+fn test_bundled_packs() {
+    assert_eq!(EnglishTranslator::pack().pack().language.code, "en");
+    assert_eq!(SpanishTranslator::pack().pack().language.code, "es");
+    assert_eq!(PortugueseTranslator::pack().pack().language.code, "pt");
+    assert_eq!(FrenchTranslator::pack().pack().language.code, "fr");
+    assert_eq!(GermanTranslator::pack().pack().language.code, "de");
+    assert_eq!(ItalianTranslator::pack().pack().language.code, "it");
+    assert_eq!(JapaneseTranslator::pack().pack().language.code, "ja");
+    assert_eq!(RussianTranslator::pack().pack().language.code, "ru");
+    assert_eq!(ChineseTranslator::pack().pack().language.code, "zh");
+}
+
+#[test]
+fn test_bundled_gendered_numbers() {
+    let options = TranslateOptions::new().use_24h(true);
+
+    // Feminine hours and masculine minutes, including inside compounds
+    let time = create_time_info(21, 21);
+    assert_eq!(
+        SpanishTranslator.translate_with(&time, &options),
+        "veintiuna veintiún"
+    );
+    let time = create_time_info(22, 32);
+    assert_eq!(
+        PortugueseTranslator.translate_with(&time, &options),
+        "vinte e duas trinta e dois"
+    );
+}
+
+#[test]
+fn test_custom_pack_tense() {
+    let pack = format!(
+        "{}\n[tense.past]\ntext = {{ one = \"estis {{phrase}}\", other = \"estis la {{phrase}}\" }}\n",
+        TOY_PACK
+    );
+    let translator = PackTranslator::new(LanguagePack::from_toml_str(&pack).unwrap());
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .tense(Tense::Past);
+
+    let time = create_time_info(1, 10);
+    assert_eq!(translator.translate_with(&time, &options), "estis post unu");
+    let time = create_time_info(2, 40);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "estis la antaŭ tri"
+    );
+
+    // A pack without a future section keeps the plain phrase
+    let options = options.tense(Tense::Future);
+    assert_eq!(translator.translate_with(&time, &options), "antaŭ tri");
+}
+
+#[test]
+fn test_plural_categories() {
+    let pack = TOY_PACK
+        .replace(
+            "name = \"Esperanto\"",
+            "name = \"Esperanto\"\nplural = \"east-slavic\"",
+        )
+        .replace(
+            "minute = { one = \"minuto\", other = \"minutoj\" }",
+            "minute = { one = \"minuto\", few = \"minutoj\", other = \"minutojn\" }",
+        );
+    let translator = PackTranslator::new(LanguagePack::from_toml_str(&pack).unwrap());
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .use_24h(true)
+        .include_units(true);
+
+    let time = create_time_info(1, 21);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "unu horo dudek unu minuto"
+    );
+    let time = create_time_info(1, 23);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "unu horo dudek tri minutoj"
+    );
+    // No `many` form, so `other` is used
+    let time = create_time_info(1, 12);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "unu horo dek du minutojn"
+    );
+}
+
+#[test]
+fn test_unknown_plural_rule_is_rejected() {
+    let broken = TOY_PACK.replace(
+        "name = \"Esperanto\"",
+        "name = \"Esperanto\"\nplural = \"dual\"",
+    );
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("dual"), "{}", err);
+}
+
+#[test]
+fn test_rounded_without_section_uses_exact() {
+    let translator = toy_translator();
+    let options = TranslateOptions::new().level(FuzzinessLevel::Rounded { step_minutes: 10 });
+
+    let time = create_time_info(3, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "tri kvindek atm"
+    );
+}
+
+#[test]
+fn test_rounded_section() {
+    let pack = format!(
+        "{}\n[rounded]\npast = \"{{minute}} post {{hour}}\"\n\n[rounded.minutes]\n30 = \"duono post {{hour}}\"\n\n[rounded.to]\nfrom = 31\ntext = \"{{minutes_to}} antaŭ {{next_hour}}\"\n",
+        TOY_PACK
+    );
+    let translator = PackTranslator::new(LanguagePack::from_toml_str(&pack).unwrap());
+    let options = TranslateOptions::new().level(FuzzinessLevel::Rounded { step_minutes: 10 });
+
+    let time = create_time_info(3, 14);
+    assert_eq!(translator.translate_with(&time, &options), "dek post tri");
+    let time = create_time_info(3, 26);
+    assert_eq!(translator.translate_with(&time, &options), "duono post tri");
+    let time = create_time_info(3, 44);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "dudek antaŭ kvar"
+    );
+}
+
+#[test]
+fn test_rounded_minute_out_of_range_is_rejected() {
+    let broken = format!(
+        "{}\n[rounded]\npast = \"post {{hour}}\"\n\n[rounded.minutes]\n60 = \"{{hour}}\"\n",
+        TOY_PACK
+    );
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("60"), "{}", err);
+}
+
+#[test]
+fn test_rounding_without_approximate_is_unqualified() {
+    let translator = toy_translator();
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .rounding(RoundingMode::Floor);
+
+    let time = create_time_info(3, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "tri kvardek kvin atm"
+    );
+}
+
+#[test]
+fn test_approximate_section() {
+    let pack = format!(
+        "{}\n[approximate]\nabout = \"ĉirkaŭ {{phrase}}\"\nalmost = \"preskaŭ {{phrase}}\"\non-the-hour = \"{{hour}}\"\n",
+        TOY_PACK
+    );
+    let translator = PackTranslator::new(LanguagePack::from_toml_str(&pack).unwrap());
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(3, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options.rounding(RoundingMode::Floor)),
+        "ĉirkaŭ tri kvardek kvin atm"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.rounding(RoundingMode::Ceiling)),
+        "ĉirkaŭ kvar"
+    );
+
+    let time = create_time_info(3, 55);
+    assert_eq!(
+        translator.translate_with(&time, &options.rounding(RoundingMode::Ceiling)),
+        "preskaŭ kvar"
+    );
+}
+
+#[test]
+fn test_approximate_without_phrase_is_rejected() {
+    let broken = format!(
+        "{}\n[approximate]\nabout = \"ĉirkaŭ\"\nalmost = \"preskaŭ {{phrase}}\"\n",
+        TOY_PACK
+    );
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("{phrase}"), "{}", err);
+}
+
+#[test]
+fn test_hour_names_and_o_clock() {
+    let pack = TOY_PACK
+        .replace("[exact]", "[hour-names]\n12 = \"tagmezo\"\n\n[exact]")
+        .replace("\"minutoj\" }\n", "\"minutoj\" }\no-clock = \"precize\"\n")
+        .replace("\"post {hour}\"", "\"{hour_name} {o_clock}\"");
+    let translator = PackTranslator::new(LanguagePack::from_toml_str(&pack).unwrap());
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    // The o'clock word takes the place of the hour label
+    let time = create_time_info(3, 10);
+    assert_eq!(
+        translator.translate_with(&time, &options.include_units(true)),
+        "tri precize"
+    );
+
+    let time = create_time_info(12, 10);
+    assert_eq!(translator.translate_with(&time, &options), "tagmezo");
+    assert_eq!(
+        translator.translate_with(&time, &options.hour_names(false)),
+        "dek du precize"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::MaxFuzzy)),
+        "tagmezo"
+    );
+
+    // Max-fuzzy only names the hour around it
+    let time = create_time_info(12, 40);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::MaxFuzzy)),
+        "mateno"
+    );
+}
+
+#[test]
+fn test_hour_name_forms_must_be_known() {
+    let pack = TOY_PACK.replace(
+        "[exact]",
+        "[hour-names]\n12 = { name = \"tagmezo\", forms = { accusative = \"tagmezon\" } }\n\n[exact]",
+    );
+    let err = LanguagePack::from_toml_str(&pack).unwrap_err();
+    assert!(err.to_string().contains("accusative"), "{}", err);
+}
+
+#[test]
+fn test_tense_without_phrase_is_rejected() {
+    let broken = format!("{}\n[tense.past]\ntext = \"estis\"\n", TOY_PACK);
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("{phrase}"), "{}", err);
+}
+
+#[test]
+fn test_number_form_placeholder() {
+    let pack = TOY_PACK
+        .replace(
+            "minute-zero = \"nul\"",
+            "minute-zero = \"nul\"\n\n[numbers.forms.short]\n1 = \"un\"",
+        )
+        .replace("post {hour}", "post {hour:short}");
+    let translator = PackTranslator::new(LanguagePack::from_toml_str(&pack).unwrap());
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(1, 10);
+    assert_eq!(translator.translate_with(&time, &options), "post un");
+    let time = create_time_info(0, 40);
+    assert_eq!(translator.translate_with(&time, &options), "antaŭ unu");
+}
+
+#[test]
+fn test_unknown_number_form_placeholder_is_rejected() {
+    let broken = TOY_PACK.replace("post {hour}", "post {hour:short}");
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("{hour:short}"), "{}", err);
+}
+
+#[test]
+fn test_style_overlay() {
+    let pack = format!(
+        "{}\n[[styles.southern.very-fuzzy]]\nminutes = [0, 59]\ntext = \"proksimume {{hour}}\"\n",
+        TOY_PACK
+    );
+    let translator = PackTranslator::new(LanguagePack::from_toml_str(&pack).unwrap());
+    let options = TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy);
+    let time = create_time_info(3, 10);
+
+    assert!(translator.supports_style(Style::Southern));
+    assert!(!translator.supports_style(Style::Northern));
+    assert_eq!(translator.translate_with(&time, &options), "ĉirkaŭ tri");
+    assert_eq!(
+        translator.translate_with(&time, &options.style(Some(Style::Southern))),
+        "proksimume tri"
+    );
+    // Everything the overlay does not replace comes from the base pack
+    assert_eq!(
+        translator.translate_with(
+            &time,
+            &options
+                .level(FuzzinessLevel::Fuzzy)
+                .style(Some(Style::Southern))
+        ),
+        "post tri"
+    );
+}
+
+#[test]
+fn test_invalid_style_overlay_is_rejected() {
+    let broken = format!(
+        "{}\n[[styles.southern.fuzzy]]\nminutes = [0, 30]\ntext = \"post {{hour}}\"\n",
+        TOY_PACK
+    );
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("southern"), "{}", err);
+
+    let unknown = format!("{}\n[styles.western]\n", TOY_PACK);
+    assert!(LanguagePack::from_toml_str(&unknown).is_err());
+}
+
+#[test]
+fn test_custom_pack_day_level() {
+    let pack = format!("{}{}", TOY_PACK, TOY_CALENDAR);
+    let translator = PackTranslator::new(LanguagePack::from_toml_str(&pack).unwrap());
+    let options = TranslateOptions::new().level(FuzzinessLevel::Day);
+
+    let time = parse_time("2024-06-10T03:00:00+00:00").unwrap();
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "malfrua dimanĉoa nokto"
+    );
+
+    // Packs without calendar phrases fall back to the day period
+    let time = parse_time("2024-06-10T03:00:00+00:00").unwrap();
+    assert_eq!(toy_translator().translate_with(&time, &options), "nokto");
+}
+
+#[test]
+fn test_invalid_calendar_is_rejected() {
+    let pack = format!("{}{}", TOY_PACK, TOY_CALENDAR);
+
+    let broken = pack.replace(", \"dimanĉo\"]", "]");
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("seven"), "{}", err);
+
+    let weekdays = pack.find("[weekdays]").unwrap()..pack.find("[day]").unwrap();
+    let broken = format!("{}{}", &pack[..weekdays.start], &pack[weekdays.end..]);
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("needs weekdays"), "{}", err);
+
+    let broken = pack.replace("malfrua {phrase}", "malfrua");
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("{phrase}"), "{}", err);
+
+    let broken = pack.replace("{weekday}a", "{weekday:acc}a");
+    assert!(LanguagePack::from_toml_str(&broken).is_err());
+}
+
+#[test]
+fn test_custom_pack_month_level() {
+    let pack = format!("{}{}", TOY_PACK, TOY_CALENDAR);
+    let translator = PackTranslator::new(LanguagePack::from_toml_str(&pack).unwrap());
+    let options = TranslateOptions::new().level(FuzzinessLevel::Month);
+
+    let time = parse_time("2024-06-15T03:00:00+00:00").unwrap();
+    assert_eq!(translator.translate_with(&time, &options), "meze de junio");
+
+    // No seasons in this pack
+    let options = options.level(FuzzinessLevel::Season);
+    assert_eq!(translator.translate_with(&time, &options), "nokto");
+}
+
+#[test]
+fn test_invalid_month_names_are_rejected() {
+    let pack = format!("{}{}", TOY_PACK, TOY_CALENDAR);
+
+    let broken = pack.replace(", \"decembro\"]", "]");
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("twelve"), "{}", err);
+
+    let months = pack.find("[months]").unwrap()..pack.find("[month]").unwrap();
+    let broken = format!("{}{}", &pack[..months.start], &pack[months.end..]);
+    let err = LanguagePack::from_toml_str(&broken).unwrap_err();
+    assert!(err.to_string().contains("needs months"), "{}", err);
+
+    let broken = pack.replace("meze de {month}", "meze de {month:gen}");
+    assert!(LanguagePack::from_toml_str(&broken).is_err());
+}
+
+#[test]
+fn test_short_number_words_fall_back_to_digits() {
+    let short = TOY_PACK.replace(
+        r#"words = ["nul", "unu", "du", "tri", "kvar", "kvin", "ses", "sep", "ok", "naŭ", "dek"]"#,
+        r#"words = ["nul", "unu", "du"]"#,
+    );
+    let translator = PackTranslator::new(LanguagePack::from_toml_str(&short).unwrap());
+
+    let time = create_time_info(5, 0);
+    assert_eq!(
+        translator.translate_with(&time, &TranslateOptions::new()),
+        "5 nul nul atm"
+    );
+    let time = create_time_info(15, 17);
+    assert_eq!(
+        translator.translate_with(&time, &TranslateOptions::new()),
+        "3 dek 7 ptm"
+    );
+}
@@ -1,22 +1,12 @@
-use rust_fuzzy_clock::time::TimeInfo;
+use rust_fuzzy_clock::time::{TimeInfo, parse_time};
+use rust_fuzzy_clock::translator::calendar::Hemisphere;
 use rust_fuzzy_clock::translator::{
-    FuzzinessLevel, TimeTranslator, portuguese::PortugueseTranslator,
+    FuzzinessLevel, Style, Tense, TimeTranslator, TranslateOptions, periods::DayPeriods,
+    portuguese::PortugueseTranslator,
 };
 
 fn create_time_info(hour24: u32, minute: u32) -> TimeInfo {
-    let is_pm = hour24 >= 12;
-    let hour12 = match hour24 {
-        0 => 12,
-        13..=23 => hour24 - 12,
-        _ => hour24,
-    };
-
-    TimeInfo {
-        hour: hour12,
-        hour24,
-        minute,
-        is_pm,
-    }
+    TimeInfo::new(hour24, minute).unwrap()
 }
 
 #[test]
@@ -25,13 +15,13 @@ fn test_exact_12h_format() {
 
     let time = create_time_info(15, 47);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "três quarenta e sete"
     );
 
     let time = create_time_info(1, 5);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "uma zero cinco"
     );
 }
@@ -42,13 +32,23 @@ fn test_exact_24h_format() {
 
     let time = create_time_info(15, 47);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, true, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .use_24h(true)
+        ),
         "quinze quarenta e sete"
     );
 
     let time = create_time_info(1, 5);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, true, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .use_24h(true)
+        ),
         "uma zero cinco"
     );
 }
@@ -60,28 +60,28 @@ fn test_fuzzy_special_times() {
     // Em ponto (on the hour)
     let time = create_time_info(3, 0);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
         "três em ponto"
     );
 
     // E quinze (quarter past)
     let time = create_time_info(9, 15);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
         "nove e quinze da manhã"
     );
 
     // E meia (half past)
     let time = create_time_info(14, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
         "duas e meia da tarde"
     );
 
     // Para (to)
     let time = create_time_info(15, 45);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
         "quinze para quatro da tarde"
     );
 }
@@ -92,14 +92,24 @@ fn test_fuzzy_24h_format() {
 
     let time = create_time_info(15, 15);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, true, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Fuzzy)
+                .use_24h(true)
+        ),
         "quinze e quinze"
     );
 
     let time = create_time_info(23, 45);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, true, false),
-        "quinze para zero"
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Fuzzy)
+                .use_24h(true)
+        ),
+        "quinze para meia-noite"
     );
 }
 
@@ -109,13 +119,19 @@ fn test_very_fuzzy() {
 
     let time = create_time_info(3, 5);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::VeryFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy)
+        ),
         "três em ponto"
     );
 
     let time = create_time_info(3, 20);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::VeryFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy)
+        ),
         "cerca de três e quinze"
     );
 }
@@ -127,37 +143,74 @@ fn test_max_fuzzy() {
     // Manhã
     let time = create_time_info(9, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "manhã"
     );
 
     // Tarde
     let time = create_time_info(14, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "tarde"
     );
 
-    // Noite (evening hours)
+    // Afternoon lasts until seven
+    let time = create_time_info(18, 30);
+    assert_eq!(
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
+        "tarde"
+    );
+
+    // Início da noite (evening hours)
     let time = create_time_info(19, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
-        "noite"
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
+        "início da noite"
     );
 
     // Noite (night hours)
     let time = create_time_info(23, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "noite"
     );
 
     // Madrugada (late night/early morning)
     let time = create_time_info(2, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "madrugada"
     );
+
+    // Night lasts until morning when the small hours get no period of their own
+    let periods = DayPeriods::parse("morning=5,afternoon=12,evening=17,night=22");
+    assert_eq!(
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::MaxFuzzy)
+                .day_periods(periods)
+        ),
+        "noite"
+    );
 }
 
 #[test]
@@ -166,13 +219,34 @@ fn test_period_suffixes() {
 
     // da manhã (morning)
     let time = create_time_info(9, 15);
-    let result = translator.translate(&time, FuzzinessLevel::Fuzzy, false, false);
+    let result =
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy));
     assert!(result.contains("da manhã"));
 
-    // da tarde (afternoon/evening)
+    // da tarde (afternoon)
     let time = create_time_info(15, 15);
-    let result = translator.translate(&time, FuzzinessLevel::Fuzzy, false, false);
+    let result =
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy));
     assert!(result.contains("da tarde"));
+
+    let time = create_time_info(18, 30);
+    assert_eq!(
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
+        "seis e meia da tarde"
+    );
+
+    // da noite (evening), never "da manhã" after six in the evening
+    let time = create_time_info(19, 15);
+    assert_eq!(
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
+        "sete e quinze da noite"
+    );
+
+    let time = create_time_info(23, 45);
+    assert_eq!(
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
+        "quinze para meia-noite"
+    );
 }
 
 #[test]
@@ -182,7 +256,7 @@ fn test_hour_one_gender() {
     // "uma" for 1 o'clock
     let time = create_time_info(1, 0);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "uma zero zero"
     );
 }
@@ -196,18 +270,19 @@ fn test_gender_aware_feminine_hours() {
     // Test feminine forms for hours
     let time = create_time_info(1, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "uma trinta"
     );
 
     let time = create_time_info(2, 0);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "duas zero zero"
     );
 
     let time = create_time_info(21, 0);
-    let result = translator.translate(&time, FuzzinessLevel::Exact, false, false);
+    let result =
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact));
     assert!(result.starts_with("nove")); // 21 in 12h = 9, feminine: nove
 }
 
@@ -217,15 +292,18 @@ fn test_gender_aware_masculine_minutes() {
 
     // Minutes use masculine form (um/dois, not uma/duas)
     let time = create_time_info(3, 1);
-    let result = translator.translate(&time, FuzzinessLevel::Exact, false, false);
+    let result =
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact));
     assert!(result.contains("zero um")); // masculine "um minuto"
 
     let time = create_time_info(3, 2);
-    let result = translator.translate(&time, FuzzinessLevel::Exact, false, false);
+    let result =
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact));
     assert!(result.contains("zero dois")); // masculine "dois minutos"
 
     let time = create_time_info(3, 21);
-    let result = translator.translate(&time, FuzzinessLevel::Exact, false, false);
+    let result =
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact));
     assert!(result.contains("vinte e um")); // masculine
 }
 
@@ -235,21 +313,36 @@ fn test_include_units_exact() {
 
     let time = create_time_info(3, 15);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .include_units(true)
+        ),
         "três horas quinze minutos"
     );
 
     // Test singular
     let time = create_time_info(1, 1);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .include_units(true)
+        ),
         "uma hora zero um minuto"
     );
 
     // Test 2 (both change with gender)
     let time = create_time_info(2, 2);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .include_units(true)
+        ),
         "duas horas zero dois minutos"
     );
 }
@@ -261,7 +354,12 @@ fn test_include_units_fuzzy() {
     // Quarter past
     let time = create_time_info(3, 15);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Fuzzy)
+                .include_units(true)
+        ),
         "três horas e quinze"
     );
 }
@@ -272,7 +370,12 @@ fn test_include_units_very_fuzzy() {
 
     let time = create_time_info(3, 5);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::VeryFuzzy, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::VeryFuzzy)
+                .include_units(true)
+        ),
         "três horas em ponto"
     );
 }
@@ -284,7 +387,189 @@ fn test_max_fuzzy_ignores_units() {
     // Max fuzzy should ignore include_units flag
     let time = create_time_info(9, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, true)
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::MaxFuzzy)
+                .include_units(true)
+        )
+    );
+}
+
+#[test]
+fn test_period_suffix_evening() {
+    let translator = PortugueseTranslator;
+
+    // 18:15 is in the afternoon/evening, not the morning
+    let time = create_time_info(18, 15);
+    assert_eq!(
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
+        "seis e quinze da tarde"
+    );
+
+    let time = create_time_info(12, 15);
+    assert_eq!(
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Fuzzy)
+                .hour_names(false)
+        ),
+        "doze e quinze"
+    );
+}
+
+#[test]
+fn test_past_and_future_tense_agreement() {
+    let translator = PortugueseTranslator;
+    let past = TranslateOptions::new()
+        .level(FuzzinessLevel::VeryFuzzy)
+        .include_units(true)
+        .tense(Tense::Past);
+    let future = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .tense(Tense::Future);
+
+    let time = create_time_info(1, 5);
+    assert_eq!(
+        translator.translate_with(&time, &past),
+        "era uma hora em ponto"
+    );
+
+    let time = create_time_info(3, 50);
+    assert_eq!(
+        translator.translate_with(&time, &future),
+        "serão quase quatro"
+    );
+
+    let time = create_time_info(12, 50);
+    assert_eq!(translator.translate_with(&time, &future), "será quase uma");
+}
+
+#[test]
+fn test_european_style() {
+    let translator = PortugueseTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .style(Some(Style::European));
+
+    let time = create_time_info(15, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "um quarto para as quatro da tarde"
+    );
+
+    let time = create_time_info(12, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "um quarto para a uma"
+    );
+
+    let time = create_time_info(9, 15);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "nove e um quarto da manhã"
+    );
+}
+
+#[test]
+fn test_european_numbers() {
+    let translator = PortugueseTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .use_24h(true);
+
+    let time = create_time_info(16, 19);
+    assert_eq!(
+        translator.translate_with(&time, &options.style(Some(Style::European))),
+        "dezasseis dezanove"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "dezesseis dezenove"
+    );
+
+    // The gendered forms are shared
+    let time = create_time_info(2, 2);
+    assert_eq!(
+        translator.translate_with(
+            &time,
+            &options.include_units(true).style(Some(Style::European))
+        ),
+        "duas horas zero dois minutos"
+    );
+}
+
+#[test]
+fn test_noon_and_midnight() {
+    let translator = PortugueseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let cases = [
+        (12, 0, "meio-dia"),
+        (12, 30, "meio-dia e meia"),
+        (23, 45, "quinze para meia-noite"),
+    ];
+    for (hour, minute, expected) in cases {
+        let time = create_time_info(hour, minute);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+
+    let time = create_time_info(11, 50);
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Future)),
+        "será quase meio-dia"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::MaxFuzzy)),
+        "meio-dia"
+    );
+}
+
+#[test]
+fn test_european_article_before_noon_and_midnight() {
+    let translator = PortugueseTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .style(Some(Style::European));
+
+    let time = create_time_info(23, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "um quarto para a meia-noite"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Past)),
+        "era um quarto para a meia-noite"
+    );
+
+    let time = create_time_info(11, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "um quarto para o meio-dia"
+    );
+}
+
+#[test]
+fn test_season_in_the_southern_hemisphere() {
+    let translator = PortugueseTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Season);
+    let time = parse_time("2024-07-10T12:00:00-03:00").unwrap();
+
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "início do verão"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.hemisphere(Some(Hemisphere::Southern))),
+        "início do inverno"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::Month)),
+        "início de julho"
     );
 }
@@ -0,0 +1,215 @@
+use rust_fuzzy_clock::output::OutputFormat;
+use rust_fuzzy_clock::reading::{FuzzyReading, TimeBucket};
+use rust_fuzzy_clock::time::sun::Coordinates;
+use rust_fuzzy_clock::time::{TimeInfo, parse_time};
+use rust_fuzzy_clock::translator::{DayPeriod, FuzzinessLevel, Language, TranslateOptions};
+
+fn create_time_info(hour24: u32, minute: u32) -> TimeInfo {
+    TimeInfo::new(hour24, minute).unwrap()
+}
+
+fn bucket(start: (u32, u32), end: (u32, u32)) -> TimeBucket {
+    TimeBucket {
+        start_minute: start.0 * 60 + start.1,
+        end_minute: end.0 * 60 + end.1,
+    }
+}
+
+#[test]
+fn test_reading_fields() {
+    let time = create_time_info(15, 40);
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+    let reading = FuzzyReading::new(Language::English, &time, &options);
+
+    assert_eq!(reading.phrase, "almost quarter to four PM");
+    assert_eq!(reading.language, "en");
+    assert_eq!(reading.fuzziness, FuzzinessLevel::Fuzzy);
+    assert_eq!(reading.hour24, 15);
+    assert_eq!(reading.minute, 40);
+    assert_eq!(reading.period, DayPeriod::Afternoon);
+}
+
+#[test]
+fn test_fuzzy_buckets() {
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let reading = FuzzyReading::new(Language::English, &create_time_info(15, 40), &options);
+    assert_eq!(reading.bucket, bucket((15, 38), (15, 44)));
+
+    // Exact phrases cover a single minute
+    let reading = FuzzyReading::new(Language::English, &create_time_info(15, 45), &options);
+    assert_eq!(reading.bucket, bucket((15, 45), (15, 45)));
+}
+
+#[test]
+fn test_very_fuzzy_bucket() {
+    let options = TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy);
+    let reading = FuzzyReading::new(Language::Spanish, &create_time_info(9, 10), &options);
+
+    assert_eq!(reading.phrase, "como nueve y cuarto");
+    assert_eq!(reading.bucket, bucket((9, 8), (9, 22)));
+}
+
+#[test]
+fn test_exact_bucket() {
+    let options = TranslateOptions::new();
+    let reading = FuzzyReading::new(Language::Portuguese, &create_time_info(1, 5), &options);
+    assert_eq!(reading.bucket, bucket((1, 5), (1, 5)));
+}
+
+#[test]
+fn test_max_fuzzy_bucket_wraps_midnight() {
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::MaxFuzzy)
+        .hour_names(false);
+    let reading = FuzzyReading::new(Language::English, &create_time_info(23, 30), &options);
+
+    assert_eq!(reading.phrase, "night");
+    assert_eq!(reading.period, DayPeriod::Night);
+    assert_eq!(reading.bucket, bucket((22, 0), (4, 59)));
+}
+
+#[test]
+fn test_language_codes() {
+    let options = TranslateOptions::new();
+    let time = create_time_info(12, 0);
+    for (language, code) in [
+        (Language::English, "en"),
+        (Language::Spanish, "es"),
+        (Language::Portuguese, "pt"),
+    ] {
+        assert_eq!(FuzzyReading::new(language, &time, &options).language, code);
+    }
+}
+
+#[test]
+fn test_json_output() {
+    let options = TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy);
+    let reading = FuzzyReading::new(Language::English, &create_time_info(3, 20), &options);
+    let json: serde_json::Value =
+        serde_json::from_str(&OutputFormat::Json.render(&reading)).unwrap();
+
+    assert_eq!(json["phrase"], "about quarter past three");
+    assert_eq!(json["language"], "en");
+    assert_eq!(json["fuzziness"], "very-fuzzy");
+    assert_eq!(json["hour24"], 3);
+    assert_eq!(json["minute"], 20);
+    assert_eq!(json["bucket"]["start_minute"], 188);
+    assert_eq!(json["bucket"]["end_minute"], 202);
+    assert_eq!(json["period"], "night");
+}
+
+#[test]
+fn test_json_rounded_bucket() {
+    let options = TranslateOptions::new().level(FuzzinessLevel::Rounded { step_minutes: 10 });
+    let reading = FuzzyReading::new(Language::English, &create_time_info(15, 22), &options);
+    let json: serde_json::Value =
+        serde_json::from_str(&OutputFormat::Json.render(&reading)).unwrap();
+
+    assert_eq!(json["phrase"], "twenty past three PM");
+    assert_eq!(json["fuzziness"], "rounded-10");
+    assert_eq!(json["bucket"]["start_minute"], 915);
+    assert_eq!(json["bucket"]["end_minute"], 924);
+}
+
+#[test]
+fn test_plain_output() {
+    let options = TranslateOptions::new();
+    let reading = FuzzyReading::new(Language::English, &create_time_info(3, 20), &options);
+    assert_eq!(OutputFormat::Plain.render(&reading), "three twenty AM");
+}
+
+#[test]
+fn test_output_format_parsing() {
+    assert_eq!(OutputFormat::parse("plain"), Some(OutputFormat::Plain));
+    assert_eq!(OutputFormat::parse("JSON"), Some(OutputFormat::Json));
+    assert_eq!(OutputFormat::parse("xml"), None);
+}
+
+#[test]
+fn test_rephrase() {
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+    let reading = FuzzyReading::new(Language::English, &create_time_info(15, 40), &options);
+
+    assert_eq!(reading.rephrase(FuzzinessLevel::Exact), "three forty PM");
+    assert_eq!(
+        reading.rephrase(FuzzinessLevel::VeryFuzzy),
+        "about quarter to four"
+    );
+    assert_eq!(reading.rephrase(FuzzinessLevel::MaxFuzzy), "afternoon");
+}
+
+#[test]
+fn test_waybar_output() {
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+    let reading = FuzzyReading::new(Language::Spanish, &create_time_info(21, 15), &options);
+    let json: serde_json::Value =
+        serde_json::from_str(&OutputFormat::Waybar.render(&reading)).unwrap();
+
+    assert_eq!(json["text"], "nueve y cuarto PM");
+    assert_eq!(json["tooltip"], "nueve quince PM");
+    assert_eq!(json["class"], "evening");
+}
+
+#[test]
+fn test_i3blocks_output() {
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+    let reading = FuzzyReading::new(Language::English, &create_time_info(9, 10), &options);
+    let rendered = OutputFormat::I3blocks.render(&reading);
+    let lines: Vec<&str> = rendered.lines().collect();
+
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], "about quarter past nine AM");
+    assert_eq!(lines[1], "about quarter past nine");
+    assert!(lines[2].starts_with('#'));
+}
+
+#[test]
+fn test_finer_period_class() {
+    let options = TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy);
+    let reading = FuzzyReading::new(Language::Chinese, &create_time_info(4, 30), &options);
+    let json: serde_json::Value =
+        serde_json::from_str(&OutputFormat::Waybar.render(&reading)).unwrap();
+
+    assert_eq!(reading.period, DayPeriod::EarlyMorning);
+    assert_eq!(json["text"], "凌晨");
+    assert_eq!(json["class"], "early-morning");
+}
+
+#[test]
+fn test_whole_day_bucket() {
+    let options = TranslateOptions::new().level(FuzzinessLevel::Month);
+    let time = parse_time("2024-03-02T12:00:00+00:00").unwrap();
+    let reading = FuzzyReading::new(Language::English, &time, &options);
+
+    assert_eq!(reading.phrase, "early March");
+    assert_eq!(reading.bucket, bucket((0, 0), (23, 59)));
+}
+
+#[test]
+fn test_sun_period_bucket_starts_at_sunset() {
+    // London at midsummer: sunset at 21:21 BST
+    let london = Coordinates::new(51.5074, -0.1278).unwrap();
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::MaxFuzzy)
+        .location(Some(london));
+    let time = parse_time("2024-06-21T21:40:00+01:00").unwrap();
+    let reading = FuzzyReading::new(Language::English, &time, &options);
+
+    assert_eq!(reading.phrase, "dusk");
+    assert_eq!(reading.period, DayPeriod::Dusk);
+    assert!((21 * 60 + 18..=21 * 60 + 25).contains(&reading.bucket.start_minute));
+    assert!(reading.bucket.end_minute < 22 * 60 + 15);
+}
+
+#[test]
+fn test_bar_formats_parsing() {
+    assert_eq!(OutputFormat::parse("waybar"), Some(OutputFormat::Waybar));
+    assert_eq!(
+        OutputFormat::parse("i3blocks"),
+        Some(OutputFormat::I3blocks)
+    );
+    assert_eq!(OutputFormat::parse("polybar"), Some(OutputFormat::Polybar));
+    assert!(OutputFormat::Polybar.keeps_running());
+    assert!(!OutputFormat::Waybar.keeps_running());
+}
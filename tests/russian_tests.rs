@@ -0,0 +1,270 @@
+use rust_fuzzy_clock::time::{TimeInfo, parse_time};
+use rust_fuzzy_clock::translator::{
+    FuzzinessLevel, ShowDay, Tense, TimeTranslator, TranslateOptions, russian::RussianTranslator,
+};
+
+fn create_time_info(hour24: u32, minute: u32) -> TimeInfo {
+    TimeInfo::new(hour24, minute).unwrap()
+}
+
+#[test]
+fn test_exact_12h_format() {
+    let translator = RussianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Exact);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "три часа сорок семь дня"
+    );
+
+    let time = create_time_info(5, 0);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "пять часов утра"
+    );
+
+    let time = create_time_info(1, 0);
+    assert_eq!(translator.translate_with(&time, &options), "один час ночи");
+}
+
+#[test]
+fn test_exact_24h_format() {
+    let translator = RussianTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .use_24h(true);
+
+    let time = create_time_info(15, 47);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "пятнадцать часов сорок семь"
+    );
+
+    let time = create_time_info(0, 5);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "ноль часов пять"
+    );
+}
+
+#[test]
+fn test_units_agree_with_number() {
+    let translator = RussianTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .use_24h(true)
+        .include_units(true);
+
+    let cases = [
+        (1, 1, "один час одна минута"),
+        (21, 22, "двадцать один час двадцать две минуты"),
+        (22, 34, "двадцать два часа тридцать четыре минуты"),
+        (11, 12, "одиннадцать часов двенадцать минут"),
+        (5, 51, "пять часов пятьдесят одна минута"),
+    ];
+    for (hour, minute, expected) in cases {
+        let time = create_time_info(hour, minute);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+}
+
+#[test]
+fn test_fuzzy_genitive_constructions() {
+    let translator = RussianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(15, 15);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "четверть четвёртого дня"
+    );
+
+    let time = create_time_info(15, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "половина четвёртого дня"
+    );
+
+    let time = create_time_info(15, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "без четверти четыре дня"
+    );
+}
+
+#[test]
+fn test_one_oclock_is_just_chas() {
+    let translator = RussianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(12, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "половина первого дня"
+    );
+
+    let time = create_time_info(12, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "без четверти час дня"
+    );
+
+    let time = create_time_info(0, 55);
+    assert_eq!(translator.translate_with(&time, &options), "почти час");
+}
+
+#[test]
+fn test_very_fuzzy() {
+    let translator = RussianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy);
+
+    let time = create_time_info(15, 5);
+    assert_eq!(translator.translate_with(&time, &options), "около трёх");
+
+    let time = create_time_info(1, 5);
+    assert_eq!(translator.translate_with(&time, &options), "около часа");
+
+    let time = create_time_info(21, 5);
+    assert_eq!(
+        translator.translate_with(&time, &options.use_24h(true)),
+        "около двадцати одного"
+    );
+
+    let time = create_time_info(15, 20);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "около четверти четвёртого"
+    );
+}
+
+#[test]
+fn test_max_fuzzy() {
+    let translator = RussianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy);
+
+    let cases = [(8, "утро"), (14, "день"), (19, "вечер"), (23, "ночь")];
+    for (hour, expected) in cases {
+        let time = create_time_info(hour, 0);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+}
+
+#[test]
+fn test_past_and_future_tense() {
+    let translator = RussianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy);
+
+    let time = create_time_info(15, 30);
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Past)),
+        "было около половины четвёртого"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Future)),
+        "будет около половины четвёртого"
+    );
+}
+
+#[test]
+fn test_rounded() {
+    let translator = RussianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Rounded { step_minutes: 5 });
+
+    let time = create_time_info(15, 53);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "без пяти четыре дня"
+    );
+
+    let time = create_time_info(15, 41);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "без двадцати четыре дня"
+    );
+
+    let time = create_time_info(15, 9);
+    assert_eq!(
+        translator.translate_with(&time, &options.include_units(true)),
+        "три часа десять минут дня"
+    );
+}
+
+#[test]
+fn test_noon_and_midnight() {
+    let translator = RussianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(12, 0);
+    assert_eq!(translator.translate_with(&time, &options), "ровно полдень");
+    assert_eq!(
+        translator.translate_with(&time, &options.hour_names(false)),
+        "ровно двенадцать часов дня"
+    );
+
+    let time = create_time_info(0, 10);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::MaxFuzzy)),
+        "полночь"
+    );
+
+    let time = create_time_info(23, 55);
+    assert_eq!(translator.translate_with(&time, &options), "почти полночь");
+    assert_eq!(
+        translator.translate_with(&time, &options.use_24h(true).hour_names(false)),
+        "почти двадцать четыре"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.hour_names(false)),
+        "почти двенадцать"
+    );
+
+    // Names have their own genitive
+    let very_fuzzy = options.level(FuzzinessLevel::VeryFuzzy);
+    let time = create_time_info(0, 5);
+    assert_eq!(
+        translator.translate_with(&time, &very_fuzzy),
+        "около полуночи"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &very_fuzzy.use_24h(true).hour_names(false)),
+        "около нуля"
+    );
+}
+
+#[test]
+fn test_day_level_uses_weekday_forms() {
+    let translator = RussianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Day);
+
+    let time = parse_time("2024-06-11T19:00:00+03:00").unwrap();
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "вечером во вторник"
+    );
+
+    let time = parse_time("2024-06-12T15:00:00+03:00").unwrap();
+    assert_eq!(translator.translate_with(&time, &options), "днём в среду");
+
+    // The attached day uses the plain name
+    let options = options
+        .level(FuzzinessLevel::MaxFuzzy)
+        .show_day(ShowDay::Weekday);
+    assert_eq!(translator.translate_with(&time, &options), "день, среда");
+}
+
+#[test]
+fn test_month_and_season_use_genitive() {
+    let translator = RussianTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Month);
+    let time = parse_time("2024-06-15T12:00:00+03:00").unwrap();
+
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "в середине июня"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::Season)),
+        "в конце весны"
+    );
+}
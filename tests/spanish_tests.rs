@@ -1,20 +1,11 @@
 use rust_fuzzy_clock::time::TimeInfo;
-use rust_fuzzy_clock::translator::{FuzzinessLevel, TimeTranslator, spanish::SpanishTranslator};
+use rust_fuzzy_clock::translator::{
+    FuzzinessLevel, RoundingMode, Style, Tense, TimeTranslator, TranslateOptions,
+    spanish::SpanishTranslator,
+};
 
 fn create_time_info(hour24: u32, minute: u32) -> TimeInfo {
-    let is_pm = hour24 >= 12;
-    let hour12 = match hour24 {
-        0 => 12,
-        13..=23 => hour24 - 12,
-        _ => hour24,
-    };
-
-    TimeInfo {
-        hour: hour12,
-        hour24,
-        minute,
-        is_pm,
-    }
+    TimeInfo::new(hour24, minute).unwrap()
 }
 
 #[test]
@@ -23,13 +14,13 @@ fn test_exact_12h_format() {
 
     let time = create_time_info(15, 47);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "tres cuarenta y siete PM"
     );
 
     let time = create_time_info(1, 5);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "una cero cinco AM"
     );
 }
@@ -40,13 +31,23 @@ fn test_exact_24h_format() {
 
     let time = create_time_info(15, 47);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, true, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .use_24h(true)
+        ),
         "quince cuarenta y siete"
     );
 
     let time = create_time_info(1, 5);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, true, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .use_24h(true)
+        ),
         "una cero cinco"
     );
 }
@@ -58,28 +59,28 @@ fn test_fuzzy_special_times() {
     // En punto (on the hour)
     let time = create_time_info(3, 0);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
         "tres en punto"
     );
 
     // Y cuarto (quarter past)
     let time = create_time_info(9, 15);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
         "nueve y cuarto AM"
     );
 
     // Y media (half past)
     let time = create_time_info(14, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
         "dos y media PM"
     );
 
     // Cuarto para (quarter to)
     let time = create_time_info(15, 45);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Fuzzy)),
         "cuarto para cuatro PM"
     );
 }
@@ -90,14 +91,24 @@ fn test_fuzzy_24h_format() {
 
     let time = create_time_info(15, 15);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, true, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Fuzzy)
+                .use_24h(true)
+        ),
         "quince y cuarto"
     );
 
     let time = create_time_info(23, 45);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, true, false),
-        "cuarto para cero"
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Fuzzy)
+                .use_24h(true)
+        ),
+        "cuarto para medianoche"
     );
 }
 
@@ -107,13 +118,19 @@ fn test_very_fuzzy() {
 
     let time = create_time_info(3, 5);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::VeryFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy)
+        ),
         "tres en punto"
     );
 
     let time = create_time_info(3, 20);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::VeryFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::VeryFuzzy)
+        ),
         "como tres y cuarto"
     );
 }
@@ -125,28 +142,40 @@ fn test_max_fuzzy() {
     // Mañana
     let time = create_time_info(9, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "mañana"
     );
 
     // Tarde
     let time = create_time_info(14, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "tarde"
     );
 
     // Atardecer
     let time = create_time_info(19, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "atardecer"
     );
 
     // Noche
     let time = create_time_info(23, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
         "noche"
     );
 }
@@ -158,13 +187,13 @@ fn test_hour_one_gender() {
     // "una" for 1 o'clock in 12h
     let time = create_time_info(1, 0);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "una cero cero AM"
     );
 
     let time = create_time_info(13, 0);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "una cero cero PM"
     );
 }
@@ -178,13 +207,13 @@ fn test_gender_aware_feminine_hours() {
     // Test numbers that change with gender - hours (feminine)
     let time = create_time_info(1, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "una treinta AM"
     );
 
     let time = create_time_info(21, 0);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, false),
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact)),
         "nueve cero cero PM"
     );
 }
@@ -195,11 +224,13 @@ fn test_gender_aware_masculine_minutes() {
 
     // Minutes use masculine form (un, not una)
     let time = create_time_info(2, 1);
-    let result = translator.translate(&time, FuzzinessLevel::Exact, false, false);
+    let result =
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact));
     assert!(result.contains("cero un")); // masculine "un minuto"
 
     let time = create_time_info(3, 21);
-    let result = translator.translate(&time, FuzzinessLevel::Exact, false, false);
+    let result =
+        translator.translate_with(&time, &TranslateOptions::new().level(FuzzinessLevel::Exact));
     assert!(result.contains("veintiún")); // masculine
 }
 
@@ -209,14 +240,24 @@ fn test_include_units_exact() {
 
     let time = create_time_info(3, 15);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .include_units(true)
+        ),
         "tres horas quince minutos AM"
     );
 
     // Test singular
     let time = create_time_info(1, 1);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Exact, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Exact)
+                .include_units(true)
+        ),
         "una hora cero un minuto AM"
     );
 }
@@ -228,7 +269,12 @@ fn test_include_units_fuzzy() {
     // Quarter past
     let time = create_time_info(3, 15);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::Fuzzy, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::Fuzzy)
+                .include_units(true)
+        ),
         "tres horas y cuarto AM"
     );
 }
@@ -239,7 +285,12 @@ fn test_include_units_very_fuzzy() {
 
     let time = create_time_info(3, 5);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::VeryFuzzy, false, true),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::VeryFuzzy)
+                .include_units(true)
+        ),
         "tres horas en punto"
     );
 }
@@ -251,7 +302,173 @@ fn test_max_fuzzy_ignores_units() {
     // Max fuzzy should ignore include_units flag
     let time = create_time_info(9, 30);
     assert_eq!(
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, false),
-        translator.translate(&time, FuzzinessLevel::MaxFuzzy, false, true)
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new().level(FuzzinessLevel::MaxFuzzy)
+        ),
+        translator.translate_with(
+            &time,
+            &TranslateOptions::new()
+                .level(FuzzinessLevel::MaxFuzzy)
+                .include_units(true)
+        )
+    );
+}
+
+#[test]
+fn test_past_and_future_tense_agreement() {
+    let translator = SpanishTranslator;
+    let past = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .tense(Tense::Past);
+    let future = TranslateOptions::new()
+        .level(FuzzinessLevel::VeryFuzzy)
+        .tense(Tense::Future);
+
+    let time = create_time_info(3, 15);
+    assert_eq!(
+        translator.translate_with(&time, &past),
+        "eran las tres y cuarto AM"
+    );
+
+    let time = create_time_info(1, 0);
+    assert_eq!(
+        translator.translate_with(&time, &past),
+        "era la una en punto"
+    );
+
+    // The verb agrees with the hour the phrase is about
+    let time = create_time_info(12, 50);
+    assert_eq!(
+        translator.translate_with(&time, &future),
+        "será casi cuarto para la una"
+    );
+    let time = create_time_info(13, 50);
+    assert_eq!(
+        translator.translate_with(&time, &future),
+        "serán casi cuarto para las dos"
+    );
+}
+
+#[test]
+fn test_present_tense_has_no_article() {
+    let translator = SpanishTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .tense(Tense::Present);
+
+    let time = create_time_info(1, 0);
+    assert_eq!(translator.translate_with(&time, &options), "una en punto");
+}
+
+#[test]
+fn test_castilian_style() {
+    let translator = SpanishTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .style(Some(Style::Castilian));
+
+    let time = create_time_info(15, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "las cuatro menos cuarto de la tarde"
+    );
+
+    let time = create_time_info(12, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "la una menos cuarto del mediodía"
+    );
+
+    let time = create_time_info(9, 10);
+    assert_eq!(
+        translator.translate_with(&time, &options.level(FuzzinessLevel::VeryFuzzy)),
+        "sobre las nueve y cuarto"
+    );
+
+    // Latin American phrasing is the default
+    let time = create_time_info(15, 45);
+    assert_eq!(
+        translator.translate_with(&time, &options.style(None)),
+        translator.translate_with(&time, &options.style(Some(Style::LatinAmerican)))
+    );
+}
+
+#[test]
+fn test_castilian_shares_gendered_numbers() {
+    let translator = SpanishTranslator;
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Exact)
+        .use_24h(true)
+        .include_units(true)
+        .style(Some(Style::Castilian));
+
+    let time = create_time_info(21, 21);
+    assert_eq!(
+        translator.translate_with(&time, &options),
+        "las veintiuna horas veintiún minutos"
+    );
+}
+
+#[test]
+fn test_rounding_modes() {
+    let translator = SpanishTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let time = create_time_info(15, 52);
+    assert_eq!(
+        translator.translate_with(&time, &options.rounding(RoundingMode::Floor)),
+        "como cuarto para cuatro PM"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.rounding(RoundingMode::Ceiling)),
+        "como cuatro PM"
+    );
+    let time = create_time_info(15, 55);
+    assert_eq!(
+        translator.translate_with(&time, &options.rounding(RoundingMode::Ceiling)),
+        "casi cuatro PM"
+    );
+
+    let castilian = options.style(Some(Style::Castilian));
+    assert_eq!(
+        translator.translate_with(&time, &castilian.rounding(RoundingMode::Ceiling)),
+        "casi las cuatro de la tarde"
+    );
+    let time = create_time_info(15, 52);
+    assert_eq!(
+        translator.translate_with(&time, &castilian.rounding(RoundingMode::Floor)),
+        "sobre las cuatro menos cuarto de la tarde"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &castilian.rounding(RoundingMode::Ceiling)),
+        "sobre las cuatro de la tarde"
+    );
+}
+
+#[test]
+fn test_noon_and_midnight() {
+    let translator = SpanishTranslator;
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+
+    let cases = [
+        (12, 0, "mediodía"),
+        (12, 15, "mediodía y cuarto"),
+        (23, 55, "casi medianoche"),
+    ];
+    for (hour, minute, expected) in cases {
+        let time = create_time_info(hour, minute);
+        assert_eq!(translator.translate_with(&time, &options), expected);
+    }
+
+    // Named hours take no article and agree like one
+    let time = create_time_info(0, 15);
+    assert_eq!(
+        translator.translate_with(&time, &options.tense(Tense::Past)),
+        "era medianoche y cuarto"
+    );
+    assert_eq!(
+        translator.translate_with(&time, &options.hour_names(false)),
+        "doce y cuarto AM"
     );
 }
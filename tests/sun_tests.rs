@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use rust_fuzzy_clock::time::sun::{Coordinates, SunPosition};
+
+fn utc(timestamp: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn london() -> Coordinates {
+    Coordinates::new(51.5074, -0.1278).unwrap()
+}
+
+#[test]
+fn test_coordinates_are_validated() {
+    assert!(Coordinates::new(90.0, 180.0).is_some());
+    assert!(Coordinates::new(-33.87, 151.21).is_some());
+    assert!(Coordinates::new(91.0, 0.0).is_none());
+    assert!(Coordinates::new(0.0, -180.5).is_none());
+    assert!(Coordinates::new(f64::NAN, 0.0).is_none());
+}
+
+#[test]
+fn test_sunrise_and_sunset_in_london() {
+    // Midsummer 2024: sunrise 03:43 UTC, sunset 20:21 UTC
+    let before = SunPosition::at(london(), utc("2024-06-21T03:38:00Z"));
+    let after = SunPosition::at(london(), utc("2024-06-21T03:48:00Z"));
+    assert!(before.elevation < SunPosition::HORIZON);
+    assert!(after.elevation > SunPosition::HORIZON);
+
+    let before = SunPosition::at(london(), utc("2024-06-21T20:16:00Z"));
+    let after = SunPosition::at(london(), utc("2024-06-21T20:26:00Z"));
+    assert!(before.elevation > SunPosition::HORIZON);
+    assert!(after.elevation < SunPosition::HORIZON);
+    assert!(after.in_twilight());
+    assert!(after.has_dawn_and_dusk());
+}
+
+#[test]
+fn test_solar_noon() {
+    // Solar noon in London on the winter solstice is at about 11:58 UTC
+    let morning = SunPosition::at(london(), utc("2024-12-21T11:50:00Z"));
+    let afternoon = SunPosition::at(london(), utc("2024-12-21T12:05:00Z"));
+    assert!(morning.hour_angle < 0.0);
+    assert!(afternoon.hour_angle > 0.0);
+    assert!((morning.highest - 15.0).abs() < 0.5);
+}
+
+#[test]
+fn test_white_nights_and_polar_night() {
+    // Reykjavik at midsummer never gets 6° below the horizon
+    let reykjavik = Coordinates::new(64.15, -21.94).unwrap();
+    let sun = SunPosition::at(reykjavik, utc("2024-06-21T01:30:00Z"));
+    assert!(sun.in_twilight());
+    assert!(!sun.has_dawn_and_dusk());
+
+    // Tromsø in December never sees the sun rise
+    let tromso = Coordinates::new(69.65, 18.96).unwrap();
+    let sun = SunPosition::at(tromso, utc("2024-12-21T10:45:00Z"));
+    assert!(sun.in_twilight());
+    assert!(sun.highest < SunPosition::HORIZON);
+    assert!(!sun.has_dawn_and_dusk());
+}
@@ -1,86 +1,285 @@
-use rust_fuzzy_clock::time::TimeInfo;
+use chrono::{FixedOffset, NaiveDate};
+use rust_fuzzy_clock::time::{
+    TimeError, TimeInfo, parse_time, parse_time_in, parse_timezone, until_next_minute,
+};
 
-// Helper function to create TimeInfo for testing
 fn create_time_info(hour24: u32, minute: u32) -> TimeInfo {
-    let is_pm = hour24 >= 12;
-    let hour12 = match hour24 {
-        0 => 12,
-        13..=23 => hour24 - 12,
-        _ => hour24,
-    };
-
-    TimeInfo {
-        hour: hour12,
-        hour24,
-        minute,
-        is_pm,
-    }
+    TimeInfo::new(hour24, minute).unwrap()
 }
 
 #[test]
 fn test_midnight_conversion() {
     let time = create_time_info(0, 0);
-    assert_eq!(time.hour, 12);
-    assert_eq!(time.hour24, 0);
-    assert!(!time.is_pm);
+    assert_eq!(time.hour(), 12);
+    assert_eq!(time.hour24(), 0);
+    assert!(!time.is_pm());
 }
 
 #[test]
 fn test_noon_conversion() {
     let time = create_time_info(12, 0);
-    assert_eq!(time.hour, 12);
-    assert_eq!(time.hour24, 12);
-    assert!(time.is_pm);
+    assert_eq!(time.hour(), 12);
+    assert_eq!(time.hour24(), 12);
+    assert!(time.is_pm());
 }
 
 #[test]
 fn test_morning_hours() {
     let time = create_time_info(9, 30);
-    assert_eq!(time.hour, 9);
-    assert_eq!(time.hour24, 9);
-    assert!(!time.is_pm);
+    assert_eq!(time.hour(), 9);
+    assert_eq!(time.hour24(), 9);
+    assert!(!time.is_pm());
 }
 
 #[test]
 fn test_afternoon_hours() {
     let time = create_time_info(15, 45);
-    assert_eq!(time.hour, 3);
-    assert_eq!(time.hour24, 15);
-    assert!(time.is_pm);
+    assert_eq!(time.hour(), 3);
+    assert_eq!(time.hour24(), 15);
+    assert!(time.is_pm());
 }
 
 #[test]
 fn test_evening_hours() {
     let time = create_time_info(23, 59);
-    assert_eq!(time.hour, 11);
-    assert_eq!(time.hour24, 23);
-    assert!(time.is_pm);
+    assert_eq!(time.hour(), 11);
+    assert_eq!(time.hour24(), 23);
+    assert!(time.is_pm());
 }
 
 #[test]
 fn test_hour_boundaries() {
     // Test boundary between 11 AM and 12 PM
     let time11 = create_time_info(11, 59);
-    assert_eq!(time11.hour, 11);
-    assert!(!time11.is_pm);
+    assert_eq!(time11.hour(), 11);
+    assert!(!time11.is_pm());
 
     let time12 = create_time_info(12, 0);
-    assert_eq!(time12.hour, 12);
-    assert!(time12.is_pm);
+    assert_eq!(time12.hour(), 12);
+    assert!(time12.is_pm());
 }
 
 #[test]
 fn test_one_am() {
     let time = create_time_info(1, 0);
-    assert_eq!(time.hour, 1);
-    assert_eq!(time.hour24, 1);
-    assert!(!time.is_pm);
+    assert_eq!(time.hour(), 1);
+    assert_eq!(time.hour24(), 1);
+    assert!(!time.is_pm());
 }
 
 #[test]
 fn test_one_pm() {
     let time = create_time_info(13, 0);
-    assert_eq!(time.hour, 1);
-    assert_eq!(time.hour24, 13);
-    assert!(time.is_pm);
+    assert_eq!(time.hour(), 1);
+    assert_eq!(time.hour24(), 13);
+    assert!(time.is_pm());
+}
+
+#[test]
+fn test_parse_time_24h() {
+    let time = parse_time("23:58").unwrap();
+    assert_eq!(time.hour24(), 23);
+    assert_eq!(time.hour(), 11);
+    assert_eq!(time.minute(), 58);
+    assert!(time.is_pm());
+
+    let time = parse_time("7:05").unwrap();
+    assert_eq!(time.hour24(), 7);
+    assert_eq!(time.minute(), 5);
+}
+
+#[test]
+fn test_parse_time_12h() {
+    let time = parse_time("3:47pm").unwrap();
+    assert_eq!(time.hour24(), 15);
+    assert_eq!(time.hour(), 3);
+    assert!(time.is_pm());
+
+    let time = parse_time("12:30 AM").unwrap();
+    assert_eq!(time.hour24(), 0);
+    assert_eq!(time.hour(), 12);
+    assert!(!time.is_pm());
+}
+
+#[test]
+fn test_parse_time_with_seconds() {
+    let time = parse_time("09:15:59").unwrap();
+    assert_eq!(time.hour24(), 9);
+    assert_eq!(time.minute(), 15);
+}
+
+#[test]
+fn test_parse_time_rfc3339() {
+    // Keeps the wall-clock time of the timestamp's own offset
+    let time = parse_time("2024-05-01T15:47:00-03:00").unwrap();
+    assert_eq!(time.hour24(), 15);
+    assert_eq!(time.minute(), 47);
+}
+
+#[test]
+fn test_parse_time_invalid() {
+    assert!(matches!(
+        parse_time("25:00"),
+        Err(TimeError::InvalidFormat(_))
+    ));
+    assert!(parse_time("12:60").is_err());
+    assert!(parse_time("13:00pm").is_err());
+    assert!(parse_time("noon").is_err());
+    assert!(parse_time("").is_err());
+}
+
+#[test]
+fn test_parse_timezone() {
+    assert!(parse_timezone("America/Sao_Paulo").is_ok());
+    assert!(parse_timezone("Europe/Madrid").is_ok());
+    assert!(parse_timezone("America/Chicago").is_ok());
+    assert!(matches!(
+        parse_timezone("Mars/Olympus_Mons"),
+        Err(TimeError::UnknownTimezone(_))
+    ));
+}
+
+#[test]
+fn test_parse_time_in_converts_rfc3339() {
+    let tz = parse_timezone("Europe/Madrid").unwrap();
+
+    // 15:47 in São Paulo (UTC-3) is 20:47 in Madrid (UTC+2 in May)
+    let time = parse_time_in("2024-05-01T15:47:00-03:00", tz).unwrap();
+    assert_eq!(time.hour24(), 20);
+    assert_eq!(time.minute(), 47);
+
+    // Wall-clock input is already local to the zone
+    let time = parse_time_in("15:47", tz).unwrap();
+    assert_eq!(time.hour24(), 15);
+}
+
+#[test]
+fn test_now_in_timezone() {
+    let tz = parse_timezone("Asia/Tokyo").unwrap();
+    let time = TimeInfo::now_in(tz);
+    assert!(time.hour24() < 24);
+    assert!(time.minute() < 60);
+}
+
+#[test]
+fn test_new_rejects_out_of_range() {
+    assert_eq!(TimeInfo::new(30, 0), Err(TimeError::InvalidHour(30)));
+    assert_eq!(TimeInfo::new(24, 0), Err(TimeError::InvalidHour(24)));
+    assert_eq!(TimeInfo::new(12, 60), Err(TimeError::InvalidMinute(60)));
+    assert!(TimeInfo::new(23, 59).is_ok());
+}
+
+#[test]
+fn test_from_12h() {
+    assert_eq!(
+        TimeInfo::from_12h(12, 0, false).unwrap(),
+        create_time_info(0, 0)
+    );
+    assert_eq!(
+        TimeInfo::from_12h(12, 30, true).unwrap(),
+        create_time_info(12, 30)
+    );
+    assert_eq!(
+        TimeInfo::from_12h(3, 47, true).unwrap(),
+        create_time_info(15, 47)
+    );
+    assert_eq!(
+        TimeInfo::from_12h(0, 0, false),
+        Err(TimeError::InvalidHour(0))
+    );
+    assert_eq!(
+        TimeInfo::from_12h(13, 0, true),
+        Err(TimeError::InvalidHour(13))
+    );
+    assert_eq!(
+        TimeInfo::from_12h(1, 75, true),
+        Err(TimeError::InvalidMinute(75))
+    );
+}
+
+#[test]
+fn test_from_chrono_types() {
+    let naive = chrono::NaiveTime::from_hms_opt(21, 5, 42).unwrap();
+    assert_eq!(TimeInfo::from(naive), create_time_info(21, 5));
+
+    let tz = parse_timezone("America/Sao_Paulo").unwrap();
+    let datetime = chrono::DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z")
+        .unwrap()
+        .with_timezone(&tz);
+    let sao_paulo = FixedOffset::west_opt(3 * 3600).unwrap();
+    let may_first = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+    assert_eq!(
+        TimeInfo::from(datetime),
+        create_time_info(9, 0).on_date(may_first, sao_paulo)
+    );
+}
+
+#[test]
+fn test_time_carries_its_date() {
+    let time = parse_time("2024-05-01T23:50:00-03:00").unwrap();
+    assert_eq!(time.date(), NaiveDate::from_ymd_opt(2024, 5, 1));
+    assert_eq!(time.utc_offset(), FixedOffset::west_opt(3 * 3600));
+    assert_eq!(
+        time.to_utc().unwrap().to_rfc3339(),
+        "2024-05-02T02:50:00+00:00"
+    );
+
+    // Rounding up past midnight moves to the next day
+    let rounded = time.ceil_to(15);
+    assert_eq!(rounded.date(), NaiveDate::from_ymd_opt(2024, 5, 2));
+    assert_eq!(time.with_time(6, 0).unwrap().date(), time.date());
+
+    let time = parse_time("23:50").unwrap();
+    assert_eq!(time.date(), None);
+    assert_eq!(time.to_utc(), None);
+}
+
+#[test]
+fn test_until_next_minute_is_within_a_minute() {
+    let wait = until_next_minute();
+    assert!(wait > std::time::Duration::ZERO);
+    assert!(wait <= std::time::Duration::from_secs(60));
+}
+
+#[test]
+fn test_round_to() {
+    let cases = [
+        ((15, 2), 5, (15, 0)),
+        ((15, 3), 5, (15, 5)),
+        ((15, 7), 15, (15, 0)),
+        ((15, 8), 15, (15, 15)),
+        ((15, 44), 30, (15, 30)),
+        ((15, 45), 30, (16, 0)),
+        ((23, 58), 5, (0, 0)),
+        ((9, 41), 1, (9, 41)),
+    ];
+    for ((hour, minute), step, (rounded_hour, rounded_minute)) in cases {
+        assert_eq!(
+            create_time_info(hour, minute).round_to(step),
+            create_time_info(rounded_hour, rounded_minute),
+            "{}:{} to {}",
+            hour,
+            minute,
+            step
+        );
+    }
+}
+
+#[test]
+fn test_floor_and_ceil_to() {
+    let cases = [
+        ((15, 0), 15, (15, 0), (15, 0)),
+        ((15, 1), 15, (15, 0), (15, 15)),
+        ((15, 14), 15, (15, 0), (15, 15)),
+        ((15, 44), 30, (15, 30), (16, 0)),
+        ((23, 50), 15, (23, 45), (0, 0)),
+    ];
+    for ((hour, minute), step, (floor_hour, floor_minute), (ceil_hour, ceil_minute)) in cases {
+        let time = create_time_info(hour, minute);
+        assert_eq!(
+            time.floor_to(step),
+            create_time_info(floor_hour, floor_minute)
+        );
+        assert_eq!(time.ceil_to(step), create_time_info(ceil_hour, ceil_minute));
+    }
 }
@@ -1,4 +1,12 @@
-use rust_fuzzy_clock::translator::{FuzzinessLevel, Language};
+use chrono::NaiveDate;
+use rust_fuzzy_clock::time::TimeInfo;
+use rust_fuzzy_clock::translator::calendar::{Hemisphere, Season, Third};
+use rust_fuzzy_clock::translator::periods::DayPeriods;
+use rust_fuzzy_clock::translator::plural::{PluralCategory, PluralRule};
+use rust_fuzzy_clock::translator::{
+    DayPeriod, FuzzinessLevel, Language, RoundingMode, ShowDay, Style, Tense, TranslateOptions,
+    get_translator,
+};
 
 #[test]
 fn test_language_parsing_english() {
@@ -48,10 +56,135 @@ fn test_language_parsing_portuguese() {
     ));
 }
 
+#[test]
+fn test_language_parsing_french() {
+    assert_eq!(Language::parse("french"), Some(Language::French));
+    assert_eq!(Language::parse("fr"), Some(Language::French));
+    assert_eq!(Language::parse("français"), Some(Language::French));
+    assert_eq!(Language::parse("FRENCH"), Some(Language::French));
+}
+
+#[test]
+fn test_language_parsing_german() {
+    assert_eq!(Language::parse("german"), Some(Language::German));
+    assert_eq!(Language::parse("de"), Some(Language::German));
+    assert_eq!(Language::parse("Deutsch"), Some(Language::German));
+}
+
+#[test]
+fn test_language_parsing_italian() {
+    assert_eq!(Language::parse("italian"), Some(Language::Italian));
+    assert_eq!(Language::parse("it"), Some(Language::Italian));
+    assert_eq!(Language::parse("Italiano"), Some(Language::Italian));
+}
+
+#[test]
+fn test_language_parsing_japanese() {
+    assert_eq!(Language::parse("japanese"), Some(Language::Japanese));
+    assert_eq!(Language::parse("ja"), Some(Language::Japanese));
+    assert_eq!(Language::parse("日本語"), Some(Language::Japanese));
+}
+
+#[test]
+fn test_language_parsing_russian() {
+    assert_eq!(Language::parse("russian"), Some(Language::Russian));
+    assert_eq!(Language::parse("ru"), Some(Language::Russian));
+    assert_eq!(Language::parse("Русский"), Some(Language::Russian));
+}
+
+#[test]
+fn test_plural_rules() {
+    assert_eq!(PluralRule::One.category(1), PluralCategory::One);
+    assert_eq!(PluralRule::One.category(0), PluralCategory::Other);
+    assert_eq!(PluralRule::ZeroAndOne.category(0), PluralCategory::One);
+    assert_eq!(PluralRule::ZeroAndOne.category(2), PluralCategory::Other);
+    assert_eq!(PluralRule::None.category(1), PluralCategory::Other);
+
+    let east_slavic = [
+        (0, PluralCategory::Many),
+        (1, PluralCategory::One),
+        (3, PluralCategory::Few),
+        (5, PluralCategory::Many),
+        (11, PluralCategory::Many),
+        (12, PluralCategory::Many),
+        (21, PluralCategory::One),
+        (22, PluralCategory::Few),
+        (111, PluralCategory::Many),
+    ];
+    for (n, category) in east_slavic {
+        assert_eq!(PluralRule::EastSlavic.category(n), category, "{}", n);
+    }
+}
+
+#[test]
+fn test_language_parsing_chinese() {
+    assert_eq!(Language::parse("chinese"), Some(Language::Chinese));
+    assert_eq!(Language::parse("zh"), Some(Language::Chinese));
+    assert_eq!(Language::parse("中文"), Some(Language::Chinese));
+}
+
+#[test]
+fn test_language_parsing_bcp47_tags() {
+    assert_eq!(Language::parse("pt-BR"), Some(Language::Portuguese));
+    assert_eq!(Language::parse("en_US"), Some(Language::English));
+    assert_eq!(Language::parse("zh-Hant-TW"), Some(Language::Chinese));
+    assert!(Language::parse("xx-PT").is_none());
+
+    assert_eq!(
+        Language::parse_tag("pt-PT"),
+        Some((Language::Portuguese, Some(Style::European)))
+    );
+    assert_eq!(
+        Language::parse_tag("pt-br"),
+        Some((Language::Portuguese, Some(Style::Brazilian)))
+    );
+    assert_eq!(
+        Language::parse_tag("es-ES"),
+        Some((Language::Spanish, Some(Style::Castilian)))
+    );
+    assert_eq!(
+        Language::parse_tag("es-MX"),
+        Some((Language::Spanish, Some(Style::LatinAmerican)))
+    );
+    assert_eq!(
+        Language::parse_tag("zh-TW"),
+        Some((Language::Chinese, Some(Style::Traditional)))
+    );
+    // Regions without a style of their own use the default phrasing
+    assert_eq!(Language::parse_tag("de-AT"), Some((Language::German, None)));
+    assert_eq!(
+        Language::parse_tag("spanish"),
+        Some((Language::Spanish, None))
+    );
+}
+
+#[test]
+fn test_language_from_locale() {
+    assert_eq!(
+        Language::from_locale("pt_BR.UTF-8"),
+        Some((Language::Portuguese, Some(Style::Brazilian)))
+    );
+    assert_eq!(
+        Language::from_locale("es_ES"),
+        Some((Language::Spanish, Some(Style::Castilian)))
+    );
+    assert_eq!(
+        Language::from_locale("de_DE@euro"),
+        Some((Language::German, None))
+    );
+    assert_eq!(
+        Language::from_locale("ja_JP.eucJP"),
+        Some((Language::Japanese, None))
+    );
+    assert!(Language::from_locale("C").is_none());
+    assert!(Language::from_locale("POSIX").is_none());
+    assert!(Language::from_locale("").is_none());
+}
+
 #[test]
 fn test_language_parsing_invalid() {
-    assert!(Language::parse("french").is_none());
-    assert!(Language::parse("fr").is_none());
+    assert!(Language::parse("klingon").is_none());
+    assert!(Language::parse("tlh").is_none());
     assert!(Language::parse("").is_none());
     assert!(Language::parse("xxx").is_none());
 }
@@ -74,6 +207,27 @@ fn test_fuzziness_parsing_valid() {
         FuzzinessLevel::parse("max-fuzzy"),
         Some(FuzzinessLevel::MaxFuzzy)
     ));
+    assert!(matches!(
+        FuzzinessLevel::parse("day"),
+        Some(FuzzinessLevel::Day)
+    ));
+    assert!(matches!(
+        FuzzinessLevel::parse("Week"),
+        Some(FuzzinessLevel::Week)
+    ));
+    assert!(matches!(
+        FuzzinessLevel::parse("month"),
+        Some(FuzzinessLevel::Month)
+    ));
+    assert!(matches!(
+        FuzzinessLevel::parse("SEASON"),
+        Some(FuzzinessLevel::Season)
+    ));
+    assert_eq!(FuzzinessLevel::Week.to_string(), "week");
+    assert_eq!(FuzzinessLevel::Season.to_string(), "season");
+    assert!(FuzzinessLevel::Month.is_date_level());
+    assert!(!FuzzinessLevel::MaxFuzzy.is_date_level());
+    assert_eq!(FuzzinessLevel::Day.step_minutes(), None);
 }
 
 #[test]
@@ -92,9 +246,190 @@ fn test_fuzziness_parsing_case_insensitive() {
     ));
 }
 
+#[test]
+fn test_fuzziness_parsing_rounded() {
+    assert_eq!(
+        FuzzinessLevel::parse("rounded"),
+        Some(FuzzinessLevel::Rounded { step_minutes: 5 })
+    );
+    assert_eq!(
+        FuzzinessLevel::parse("Rounded-15"),
+        Some(FuzzinessLevel::Rounded { step_minutes: 15 })
+    );
+    assert!(FuzzinessLevel::parse("rounded-7").is_none());
+    assert!(FuzzinessLevel::parse("rounded-0").is_none());
+    assert!(FuzzinessLevel::parse("rounded-").is_none());
+
+    assert_eq!(
+        FuzzinessLevel::Rounded { step_minutes: 10 }.to_string(),
+        "rounded-10"
+    );
+    assert_eq!(FuzzinessLevel::VeryFuzzy.to_string(), "very-fuzzy");
+}
+
 #[test]
 fn test_fuzziness_parsing_invalid() {
     assert!(FuzzinessLevel::parse("approximate").is_none());
     assert!(FuzzinessLevel::parse("").is_none());
     assert!(FuzzinessLevel::parse("very fuzzy").is_none());
 }
+
+#[test]
+fn test_translate_options_defaults() {
+    let options = TranslateOptions::default();
+    assert_eq!(options.level, FuzzinessLevel::Exact);
+    assert!(!options.use_24h);
+    assert!(!options.include_units);
+    assert_eq!(options.tense, Tense::Present);
+    assert_eq!(TranslateOptions::new(), options);
+}
+
+#[test]
+fn test_translate_options_builder() {
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::VeryFuzzy)
+        .use_24h(true)
+        .include_units(true);
+    assert_eq!(options.level, FuzzinessLevel::VeryFuzzy);
+    assert!(options.use_24h);
+    assert!(options.include_units);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_translate_matches_translate_with() {
+    let translator = get_translator(Language::English);
+    let time = TimeInfo::new(15, 47).unwrap();
+
+    let options = TranslateOptions::new()
+        .level(FuzzinessLevel::Fuzzy)
+        .include_units(true);
+    assert_eq!(
+        translator.translate(&time, FuzzinessLevel::Fuzzy, false, true),
+        translator.translate_with(&time, &options)
+    );
+}
+
+#[test]
+fn test_tense_parsing() {
+    assert_eq!(Tense::parse("present"), Some(Tense::Present));
+    assert_eq!(Tense::parse("Past"), Some(Tense::Past));
+    assert_eq!(Tense::parse("FUTURE"), Some(Tense::Future));
+    assert!(Tense::parse("perfect").is_none());
+    assert!(Tense::parse("").is_none());
+}
+
+#[test]
+fn test_show_day_parsing() {
+    assert_eq!(ShowDay::parse("off"), Some(ShowDay::Off));
+    assert_eq!(ShowDay::parse("none"), Some(ShowDay::Off));
+    assert_eq!(ShowDay::parse("Weekday"), Some(ShowDay::Weekday));
+    assert_eq!(ShowDay::parse("RELATIVE"), Some(ShowDay::Relative));
+    assert!(ShowDay::parse("date").is_none());
+    assert_eq!(TranslateOptions::new().show_day, ShowDay::Off);
+}
+
+#[test]
+fn test_seasons_by_hemisphere() {
+    let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+    let north = |m, d| Season::of(date(m, d), Hemisphere::Northern);
+    let south = |m, d| Season::of(date(m, d), Hemisphere::Southern);
+
+    assert_eq!(north(6, 20), (Season::Spring, Third::Late));
+    assert_eq!(north(6, 21), (Season::Summer, Third::Early));
+    assert_eq!(north(8, 1), (Season::Summer, Third::Middle));
+    assert_eq!(north(1, 5), (Season::Winter, Third::Early));
+    assert_eq!(north(3, 1), (Season::Winter, Third::Late));
+    assert_eq!(south(7, 10), (Season::Winter, Third::Early));
+    assert_eq!(south(12, 25), (Season::Summer, Third::Early));
+
+    assert_eq!(Third::of_month(date(6, 10)), Third::Early);
+    assert_eq!(Third::of_month(date(6, 11)), Third::Middle);
+    assert_eq!(Third::of_month(date(2, 29)), Third::Late);
+
+    assert_eq!(Hemisphere::parse("South"), Some(Hemisphere::Southern));
+    assert_eq!(Hemisphere::parse("northern"), Some(Hemisphere::Northern));
+    assert!(Hemisphere::parse("east").is_none());
+}
+
+#[test]
+fn test_rounding_mode_parsing() {
+    assert_eq!(RoundingMode::parse("nearest"), Some(RoundingMode::Nearest));
+    assert_eq!(RoundingMode::parse("Floor"), Some(RoundingMode::Floor));
+    assert_eq!(RoundingMode::parse("CEILING"), Some(RoundingMode::Ceiling));
+    assert!(RoundingMode::parse("up").is_none());
+    assert_eq!(TranslateOptions::new().rounding, RoundingMode::Nearest);
+}
+
+#[test]
+fn test_rounding_mode_round() {
+    let time = TimeInfo::new(15, 37).unwrap();
+    let round = |mode: RoundingMode, step| {
+        let rounded = mode.round(&time, step);
+        (rounded.hour24(), rounded.minute())
+    };
+    assert_eq!(round(RoundingMode::Nearest, 15), (15, 30));
+    assert_eq!(round(RoundingMode::Floor, 15), (15, 30));
+    assert_eq!(round(RoundingMode::Ceiling, 15), (15, 45));
+    assert_eq!(round(RoundingMode::Ceiling, 30), (16, 0));
+
+    assert_eq!(FuzzinessLevel::Fuzzy.step_minutes(), Some(15));
+    assert_eq!(FuzzinessLevel::VeryFuzzy.step_minutes(), Some(30));
+    assert_eq!(
+        FuzzinessLevel::Rounded { step_minutes: 10 }.step_minutes(),
+        Some(10)
+    );
+    assert_eq!(FuzzinessLevel::Exact.step_minutes(), None);
+}
+
+#[test]
+fn test_day_periods_parsing() {
+    let periods = DayPeriods::parse("night=22, morning=5,afternoon=12,evening=17").unwrap();
+    assert_eq!(periods, DayPeriods::default());
+    assert_eq!(
+        periods.to_string(),
+        "morning=5,afternoon=12,evening=17,night=22"
+    );
+    assert_eq!(periods.start(DayPeriod::Evening), Some(17));
+    assert_eq!(periods.start(DayPeriod::Noon), None);
+
+    assert!(DayPeriods::parse("").is_none());
+    assert!(DayPeriods::parse("brunch=10").is_none());
+    assert!(DayPeriods::parse("morning=24").is_none());
+    assert!(DayPeriods::parse("morning=5,night=5").is_none());
+    assert!(DayPeriods::parse("morning=5,morning=6").is_none());
+    assert!(DayPeriods::parse("morning").is_none());
+    assert_eq!(TranslateOptions::new().day_periods, None);
+}
+
+#[test]
+fn test_day_periods_period_at() {
+    let periods = DayPeriods::default();
+    assert_eq!(periods.period_at(4), DayPeriod::Night);
+    assert_eq!(periods.period_at(5), DayPeriod::Morning);
+    assert_eq!(periods.period_at(16), DayPeriod::Afternoon);
+    assert_eq!(periods.period_at(21), DayPeriod::Evening);
+    assert_eq!(periods.period_at(23), DayPeriod::Night);
+
+    let night_shift = DayPeriods::parse("morning=18,afternoon=0,evening=6,night=10").unwrap();
+    assert_eq!(night_shift.period_at(19), DayPeriod::Morning);
+    assert_eq!(night_shift.period_at(2), DayPeriod::Afternoon);
+    assert_eq!(night_shift.period_at(12), DayPeriod::Night);
+}
+
+#[test]
+fn test_style_parsing() {
+    assert_eq!(Style::parse("northern"), Some(Style::Northern));
+    assert_eq!(Style::parse("Southern"), Some(Style::Southern));
+    assert_eq!(Style::parse("KANA"), Some(Style::Kana));
+    assert_eq!(Style::parse("traditional"), Some(Style::Traditional));
+    assert!(Style::parse("western").is_none());
+    assert_eq!(Style::Southern.as_str(), "southern");
+}
+
+#[test]
+fn test_languages_without_styles() {
+    let translator = get_translator(Language::English);
+    assert!(!translator.supports_style(Style::Southern));
+    assert_eq!(TranslateOptions::new().style, None);
+}
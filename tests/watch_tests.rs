@@ -0,0 +1,52 @@
+use rust_fuzzy_clock::reading::FuzzyReading;
+use rust_fuzzy_clock::time::TimeInfo;
+use rust_fuzzy_clock::translator::{FuzzinessLevel, Language, TranslateOptions};
+use rust_fuzzy_clock::watch;
+
+// Watches the given minutes, one per tick, and returns what was printed
+fn watch_minutes<F>(minutes: &[(u32, u32)], format: F) -> String
+where
+    F: Fn(&FuzzyReading) -> String,
+{
+    let options = TranslateOptions::new().level(FuzzinessLevel::Fuzzy);
+    let mut times = minutes
+        .iter()
+        .map(|&(hour24, minute)| TimeInfo::new(hour24, minute).unwrap());
+    let read = || FuzzyReading::new(Language::English, &times.next().unwrap(), &options);
+
+    let mut out = Vec::new();
+    watch::run_with_ticks(read, format, &mut out, 1..minutes.len()).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn test_prints_only_when_the_phrase_changes() {
+    let printed = watch_minutes(
+        &[(15, 31), (15, 33), (15, 40), (15, 43), (15, 44), (15, 45)],
+        |reading| reading.phrase.clone(),
+    );
+    assert_eq!(
+        printed,
+        "about half past three PM\nalmost quarter to four PM\nquarter to four PM\n"
+    );
+}
+
+#[test]
+fn test_prints_every_change_of_the_formatted_line() {
+    // A format that shows the minute changes every time
+    let printed = watch_minutes(&[(15, 40), (15, 41), (15, 41)], |reading| {
+        format!("{} ({})", reading.phrase, reading.minute)
+    });
+    assert_eq!(
+        printed,
+        "almost quarter to four PM (40)\nalmost quarter to four PM (41)\n"
+    );
+}
+
+#[test]
+fn test_reads_once_without_ticks() {
+    assert_eq!(
+        watch_minutes(&[(9, 0)], |reading| reading.phrase.clone()),
+        "nine o'clock\n"
+    );
+}